@@ -1,15 +1,64 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, token, Address, Env, String, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
-mod test;
+mod test_v2;
 
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
 
+/// Why a vote was refused under the conflict-of-interest rules
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VoteError {
+    FounderVote = 1,
+    TeamMemberVote = 2,
+    InvestorVote = 3,
+    RelatedParty = 4,
+}
+
+/// A vote signed off-chain with the voter's registered vote key, submitted
+/// by a relayer. The signature covers (contract id, founder, vote_yes, nonce).
+#[derive(Clone)]
+#[contracttype]
+pub struct SignedVote {
+    pub voter: Address,
+    pub founder: Address,
+    pub vote_yes: bool,
+    pub nonce: u64,
+    pub signature: BytesN<64>,
+    pub proof: Vec<BytesN<32>>,      // Allowlist proof, not covered by the signature
+}
+
+/// How votes from addresses already invested in the startup are treated
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum InvestorVotePolicy {
+    Allowed,
+    Barred,
+    Separate,                        // Tallied apart from the public vote
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ConflictRules {
+    pub bar_team: bool,              // Founder and team members can't vote on their startup
+    pub investor_policy: InvestorVotePolicy,
+}
+
+/// Votes cast by existing investors under `InvestorVotePolicy::Separate`
+#[derive(Clone)]
+#[contracttype]
+pub struct InvestorTally {
+    pub yes_votes: u32,
+    pub no_votes: u32,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub struct StartupData {
@@ -25,6 +74,12 @@ pub struct StartupData {
     pub milestone_count: u32,        // NEW: Track milestones
     pub current_milestone: u32,      // NEW: Current milestone (0-3 for 25% each)
     pub last_milestone_time: u64,    // NEW: Timestamp of last milestone unlock
+    pub refunded_balance: i128,      // Escrow returned to investors
+    pub investor_count: u32,
+    pub startup_id: u64,
+    pub application_id: u64,         // Current application round
+    pub community_allocation: i128,  // Reserved for community_invest
+    pub community_raised: i128,
 }
 
 #[derive(Clone)]
@@ -34,12 +89,390 @@ pub struct VCData {
     pub company_name: String,
     pub stake_amount: i128,
     pub total_invested: i128,
+    pub metadata_cid: Option<String>,    // Website, thesis and team on IPFS
+    pub verified: bool,                  // Set by admin or a KYC attester
+}
+
+/// VC tier, derived from stake size
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[contracttype]
+pub enum VCTier {
+    Angel,
+    SeedFund,
+    Lead,
+}
+
+/// Stake thresholds and per-tier maximum tickets (zero disables a limit)
+#[derive(Clone)]
+#[contracttype]
+pub struct TierConfig {
+    pub seed_fund_stake: i128,
+    pub lead_stake: i128,
+    pub angel_max_ticket: i128,
+    pub seed_fund_max_ticket: i128,
+    pub lead_max_ticket: i128,
+}
+
+/// Per-startup share issuance terms set by the founder
+#[derive(Clone)]
+#[contracttype]
+pub struct ShareTerms {
+    pub pre_money_valuation: i128,
+    pub founder_shares: i128,
+    pub total_shares: i128,
+    pub token: Address,                 // Stellar asset administered by this contract
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CapTableEntry {
+    pub holder: Address,
+    pub shares: i128,
+}
+
+/// Limits applied to every `vc_invest` call (zero disables a limit)
+#[derive(Clone)]
+#[contracttype]
+pub struct InvestmentLimits {
+    pub overfunding_bps: u32,    // Allowed raise above funding_goal
+    pub min_ticket: i128,
+    pub max_ticket: i128,        // Per VC, cumulative per startup
+    pub max_investors: u32,      // Per startup
+}
+
+/// Funding goal bounds enforced on `apply` (zero max disables the upper bound)
+#[derive(Clone)]
+#[contracttype]
+pub struct FundingBounds {
+    pub min_funding_goal: i128,
+    pub max_funding_goal: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct MetadataRevision {
+    pub ipfs_cid: String,
+    pub updated_at: u64,
+}
+
+/// Co-founder team that owns a startup. Once set, claims and team changes
+/// need `threshold` member signatures and payouts are split by `payout_bps`.
+#[derive(Clone)]
+#[contracttype]
+pub struct Team {
+    pub members: Vec<Address>,
+    pub payout_bps: Vec<u32>,
+    pub threshold: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ApplicationOutcome {
+    Pending,
+    Approved,
+    Rejected,
+    Completed,
+    Failed,
+    Terminated,                      // Investors voted to wind the startup down
+}
+
+/// One funding application; a founder accumulates one per round
+#[derive(Clone)]
+#[contracttype]
+pub struct ApplicationRecord {
+    pub application_id: u64,
+    pub founder: Address,
+    pub ipfs_cid: String,
+    pub funding_goal: i128,
+    pub submitted_at: u64,
+    pub closed_at: u64,
+    pub yes_votes: u32,
+    pub no_votes: u32,
+    pub outcome: ApplicationOutcome,
+}
+
+/// Investor vote to terminate a funded startup and reclaim its escrow
+#[derive(Clone)]
+#[contracttype]
+pub struct TerminationProposal {
+    pub proposer: Address,
+    pub created_at: u64,
+    pub voting_end: u64,
+    pub votes_for: i128,             // Weighted by current-round investment
+    pub votes_against: i128,
+}
+
+/// How much of a round's investment must back a termination
+#[derive(Clone)]
+#[contracttype]
+pub struct TerminationRules {
+    pub quorum_bps: u32,             // Share of total_allocated that must vote for
+    pub voting_period: u64,
+}
+
+/// One entry in a startup's milestone schedule
+#[derive(Clone)]
+#[contracttype]
+pub struct MilestoneInfo {
+    pub index: u32,
+    pub amount: i128,                // Released by this milestone, projected for later ones
+    pub unlocked: bool,
+    pub eligible_at: u64,            // Earliest unlock by the interval, projected for later milestones
+    pub kpi_gated: bool,             // Unlocks by oracle attestation instead
+    pub eligible: bool,              // Could be unlocked right now
+}
+
+/// Everything the frontend shows for one startup, from one viewer's side
+#[derive(Clone)]
+#[contracttype]
+pub struct StartupDetails {
+    pub startup: StartupData,
+    pub status: ApplicationOutcome,
+    pub funding_progress_bps: u32,
+    pub voting_time_remaining: u64,
+    pub viewer_vote: Option<bool>,   // None if the viewer hasn't voted this round
+    pub viewer_investment: i128,
+    pub milestones: Vec<MilestoneInfo>,
+}
+
+/// A startup in an investor's portfolio
+#[derive(Clone)]
+#[contracttype]
+pub struct PortfolioEntry {
+    pub startup: Address,
+    pub amount: i128,                // Lifetime Investment position
+    pub status: ApplicationOutcome,  // Outcome of the startup's current round
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct InvestorEntry {
+    pub investor: Address,
+    pub amount: i128,
+}
+
+/// One payout of unlocked funds
+#[derive(Clone)]
+#[contracttype]
+pub struct SpendingRecord {
+    pub application_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub purpose_cid: Option<String>, // None for plain claim_funds payouts
+    pub claimed_at: u64,
+}
+
+/// What happens when a milestone is still locked after its deadline and grace
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum MissedMilestonePenalty {
+    PartialReturn,                   // Part of the tranche goes back to investors
+    InvestorVote,                    // A termination vote opens automatically
+    AtRisk,                          // Startup is flagged until the milestone unlocks
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct DeadlineRules {
+    pub deadline: u64,               // Seconds after the previous milestone (0 = no deadline)
+    pub grace_period: u64,
+    pub penalty: MissedMilestonePenalty,
+    pub return_bps: u32,             // Share of the tranche returned under PartialReturn
+}
+
+/// Founder's request for more time on the current milestone
+#[derive(Clone)]
+#[contracttype]
+pub struct ExtensionRequest {
+    pub extra_time: u64,
+    pub requested_at: u64,
+    pub expires_at: u64,             // Voting closes after one investor voting period
+    pub votes_for: i128,             // Weighted by current-round investment
+    pub votes_against: i128,
+}
+
+/// Milestone that unlocks when a registered oracle attests a KPI threshold
+#[derive(Clone)]
+#[contracttype]
+pub struct KpiMilestone {
+    pub kpi: Symbol,                 // e.g. "mrr", "users"
+    pub threshold: i128,
+    pub oracle: Address,             // Account or oracle contract
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct KpiAttestation {
+    pub value: i128,
+    pub attested_at: u64,
+}
+
+/// What a dispute is about
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum ClaimType {
+    MilestoneUnlock,                 // Founder contests a withheld milestone
+    Refund,                          // Investors want the remaining escrow back
+}
+
+/// Binding arbiter decision on the disputed escrow
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum Ruling {
+    Release,                         // Disputed amount is unlocked for the founder
+    Refund,                          // Disputed amount is returned to investors
+    Split,                           // Half of each
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum DisputeStatus {
+    Open,
+    Released,
+    Refunded,
+    Split,
+    Dismissed,                       // No arbiter voted; escrow stays put
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Dispute {
+    pub dispute_id: u64,
+    pub founder: Address,
+    pub application_id: u64,
+    pub opened_by: Address,
+    pub claim_type: ClaimType,
+    pub evidence_cid: String,
+    pub amount: i128,                // Escrow at stake when the dispute was opened
+    pub opened_at: u64,
+    pub voting_end: u64,
+    pub release_votes: u32,
+    pub refund_votes: u32,
+    pub split_votes: u32,
+    pub status: DisputeStatus,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ArbiterData {
+    pub stake_amount: i128,
+    pub open_votes: u32,             // Votes on disputes not yet resolved
+}
+
+/// Cap on treasury withdrawals within a rolling period
+#[derive(Clone)]
+#[contracttype]
+pub struct SpendLimit {
+    pub max_per_period: i128,
+    pub period: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct SpendWindow {
+    pub window_start: u64,
+    pub spent: i128,
+}
+
+/// Breakdown of what the contract owes against what it holds in a token
+#[derive(Clone)]
+#[contracttype]
+pub struct Reconciliation {
+    pub stakes: i128,
+    pub escrowed: i128,
+    pub treasury: i128,
+    pub voter_rewards: i128,
+    pub revenue: i128,
+    pub token_balance: i128,
+    pub balanced: bool,
+}
+
+/// Aggregate ledger across all VCs and the current round of every startup
+#[derive(Clone)]
+#[contracttype]
+pub struct AuditTotals {
+    pub total_stakes: i128,              // VC and arbiter stakes
+    pub total_escrow: i128,              // Invested but not yet unlocked or refunded
+    pub total_unlocked_unclaimed: i128,
+    pub total_claimed: i128,
+    pub total_refunded: i128,
+    pub total_revenue_unclaimed: i128,   // Deposited revenue not yet paid to investors
+}
+
+/// Track record of a public voter
+#[derive(Clone)]
+#[contracttype]
+pub struct VoterStats {
+    pub reputation: i64,
+    pub correct_calls: u32,
+    pub incorrect_calls: u32,
+}
+
+/// A staked VC's due-diligence review of an application
+#[derive(Clone)]
+#[contracttype]
+pub struct ReviewRecord {
+    pub reviewer: Address,
+    pub score: u32,              // 0-100
+    pub report_cid: String,
+    pub submitted_at: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ReviewSummary {
+    pub review_count: u32,
+    pub total_score: u32,
+    pub average_score: u32,
+}
+
+/// A lead investor's syndicate for one startup
+#[derive(Clone)]
+#[contracttype]
+pub struct Syndicate {
+    pub lead: Address,
+    pub carry_bps: u32,          // Lead's cut of members' shares and returns
+    pub min_ticket: i128,
+    pub total_raised: i128,
+    pub member_count: u32,
+    pub open: bool,
+}
+
+/// Revenue a startup shares with its investors, distributed pro rata to
+/// `Investment` positions through a cumulative per-unit accumulator
+#[derive(Clone)]
+#[contracttype]
+pub struct RevenuePool {
+    pub token: Option<Address>,
+    pub acc_per_unit: i128,          // Scaled by REVENUE_PRECISION
+    pub total_units: i128,           // Sum of all Investment positions
+    pub total_deposited: i128,
+    pub total_claimed: i128,
+    pub payback_cap_bps: u32,        // Max total payback as a multiple of positions (0 = uncapped)
+}
+
+/// Founder-designated buyer who may match any position sale first
+#[derive(Clone)]
+#[contracttype]
+pub struct RightOfFirstRefusal {
+    pub holder: Address,
+    pub window: u64,                 // Seconds the holder has to match an offer
+}
+
+/// Position a seller has put up for sale while a ROFR applies
+#[derive(Clone)]
+#[contracttype]
+pub struct PositionOffer {
+    pub amount: i128,
+    pub price: i128,
+    pub offered_at: u64,
 }
 
 #[derive(Clone)]
 #[contracttype]
 pub struct ContractConfig {
     pub admin: Address,
+    pub token: Address,                  // Token held in escrow, stakes and fees
     pub application_fee: i128,
     pub vc_stake_required: i128,
     pub paused: bool,                    // NEW: Circuit breaker
@@ -47,18 +480,184 @@ pub struct ContractConfig {
     pub milestone_interval: u64,         // NEW: Time between milestones (seconds)
 }
 
+/// Functions that can be paused independently of each other
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[contracttype]
+pub enum PauseFlag {
+    Applications,
+    Voting,
+    Investing,
+    Claiming,
+    Staking,
+}
+
 #[contracttype]
 pub enum DataKey {
     Config,
     Startup(Address),
     VCData(Address),
-    Vote(Address, Address),
     AllStartups,
     AllVCs,
     Investment(Address, Address),
     ReentrancyGuard(Address),           // NEW: Reentrancy protection
+    Paused(PauseFlag),                  // Per-function circuit breaker
+    Shutdown,                           // Emergency shutdown (irreversible)
+    RefundClaimed(u64, Address),        // (application_id, investor) -> escrow refunded
+    ShareTerms(Address),                // founder -> ShareTerms
+    InvestmentLimits,
+    FundingBounds,
+    MetadataHistory(u64),               // application_id -> Vec<MetadataRevision>
+    NextStartupId,
+    StartupById(u64),                   // startup_id -> founder
+    Team(Address),                      // founder -> Team
+    FounderKey(Address),                // founder -> rotated key acting for the startup
+    NextApplicationId,
+    Application(u64),                   // application_id -> ApplicationRecord
+    FounderApplications(Address),       // founder -> Vec<application_id>
+    ApplicationVote(u64, Address),      // (application_id, voter) -> vote_yes
+    ApplicationInvestment(u64, Address), // (application_id, investor) -> amount this round
+    ReapplyCooldown,
+    FeeToken,                           // Token application fees are paid in
+    PlatformFeeBps,                     // Take-rate on every vc_invest
+    Treasury(Address),                  // token -> contract-owned balance
+    TreasurySpendLimit,
+    TreasurySpent(Address),             // token -> SpendWindow
+    InvariantChecks,                    // Check solvency after every mutation
+    VoterStats(Address),                // voter -> VoterStats (persistent)
+    RewardPoolBps,                      // Share of each application fee paid to voters
+    RewardPot(u64),                     // application_id -> fee token held for its voters
+    VoterRewards(Address),              // voter -> claimable fee token (persistent)
+    RewardLiabilities,                  // Pots, unsettled backer shares and unclaimed voter rewards
+    Review(u64, Address),               // (application_id, vc) -> ReviewRecord
+    Reviewers(u64),                     // application_id -> Vec<Address>
+    MinReviews,                         // Reviews required before approval
+    TierConfig,
+    KycAttester(Address),
+    Syndicate(Address, Address),        // (founder, lead) -> Syndicate
+    SyndicateLeads(Address),            // founder -> Vec<Address>
+    SyndicateMember(Address, Address),  // (founder, member) -> lead
+    SyndicateContribution(Address, Address), // (founder, member) -> amount escrowed
+    CommunityUserCap,                   // Max community contribution per user per round
+    CommunityContribution(u64, Address), // (application_id, user) -> amount escrowed
+}
+
+// DataKey is at the 50-case limit of a contracttype union, so newer
+// features keep their storage keys in their own enums. Only the variant
+// name and fields are serialized, so variant names must stay unique
+// across all key enums.
+#[contracttype]
+pub enum RevenueKey {
+    Pool(Address),                      // founder -> RevenuePool
+    Debt(Address, Address),             // (founder, investor) -> accumulator snapshot
+    Owed(Address, Address),             // (founder, investor) -> settled, unclaimed revenue
+}
+
+#[contracttype]
+pub enum TerminationKey {
+    Rules,
+    Proposal(u64),                      // application_id -> TerminationProposal
+    TerminationVote(u64, Address),      // (application_id, investor) -> created_at of the proposal voted on
+    ClosedRound(u64),                   // application_id -> StartupData of a round the founder reapplied past
+    ClosedRounds(Address),              // founder -> Vec<u64> of closed rounds still holding escrow
+}
+
+#[contracttype]
+pub enum DisputeKey {
+    ArbiterStake,                       // Stake required to join the panel
+    VotingPeriod,
+    Arbiter(Address),                   // arbiter -> ArbiterData
+    Arbiters,                           // Vec<Address>
+    NextDisputeId,
+    Dispute(u64),                       // dispute_id -> Dispute
+    Open(Address),                      // founder -> dispute_id currently open
+    ArbiterVote(u64, Address),          // (dispute_id, arbiter) -> Ruling
+    Voters(u64),                        // dispute_id -> Vec<Address>
+    RefundPool(u64),                    // application_id -> escrow ruled back to investors
+    RefundPaid(u64, Address),           // (application_id, investor) -> paid from the pool
+    MinOpenerShare,                     // bps of the round an investor needs to open a dispute
+    OpenerCooldown(Address, Address),   // (founder, opener) -> time they may open again
+}
+
+#[contracttype]
+pub enum OracleKey {
+    Oracle(Address),                    // oracle -> registered
+    Kpi(u64, u32),                      // (application_id, milestone) -> KpiMilestone
+    Attestation(u64, u32),              // (application_id, milestone) -> latest KpiAttestation
+}
+
+#[contracttype]
+pub enum DeadlineKey {
+    DeadlineRules,
+    Extension(u64, u32),                // (application_id, milestone) -> approved extra time
+    Request(u64),                       // application_id -> open ExtensionRequest
+    ExtensionVote(u64, Address),        // (application_id, investor) -> requested_at of the request voted on
+    Penalized(u64, u32),                // (application_id, milestone) -> penalty applied
+    Tranche(u64, u32),                  // (application_id, milestone) -> amount unlocked
+    AtRisk(u64),                        // application_id -> flagged
+}
+
+#[contracttype]
+pub enum ConflictKey {
+    ConflictRules,
+    RelatedParty(Address, Address),     // (founder, party) -> barred from voting
+    InvestorTally(u64),                 // application_id -> InvestorTally
 }
 
+#[contracttype]
+pub enum SignedVoteKey {
+    VoteKey(Address),                   // voter -> ed25519 public key (persistent)
+    VoteNonce(Address),                 // voter -> next expected nonce (persistent)
+    KeyOwner(BytesN<32>),               // ed25519 public key -> voter (persistent)
+}
+
+#[contracttype]
+pub enum EligibilityKey {
+    VoterRoot(u64),                     // application_id -> Merkle root of eligible voters
+    Eligible(u64, Address),             // (application_id, voter) -> proof already accepted (persistent)
+    AllowlistRequired,                  // Voting waits for each round's root to be published
+}
+
+#[contracttype]
+pub enum RewardKey {
+    Backers(u64),                       // application_id -> counted yes votes
+    BackerShare(u64),                   // application_id -> reward owed to each backer
+    Unsettled(u64, Address),            // (application_id, voter) -> counted vote not yet settled (persistent)
+}
+
+// Indexes are append-only persistent lists, so offsets stay stable when a
+// position closes; views skip closed entries
+#[contracttype]
+pub enum IndexKey {
+    PortfolioLen(Address),              // investor -> number of startups ever held
+    Portfolio(Address, u32),            // (investor, index) -> founder
+    InPortfolio(Address, Address),      // (investor, founder) -> already listed
+    InvestorsLen(Address),              // founder -> number of investors ever listed
+    Investors(Address, u32),            // (founder, index) -> investor
+    InInvestors(Address, Address),      // (founder, investor) -> already listed
+    CapTableLen(Address),               // founder -> number of share holders ever listed
+    CapTable(Address, u32),             // (founder, index) -> share holder
+    InCapTable(Address, Address),       // (founder, holder) -> already listed
+}
+
+#[contracttype]
+pub enum ClaimKey {
+    SpendingCount(Address),             // founder -> number of spending records (persistent)
+    Spending(Address, u32),             // (founder, index) -> SpendingRecord (persistent)
+}
+
+#[contracttype]
+pub enum PositionKey {
+    Rofr(Address),                      // founder -> RightOfFirstRefusal
+    Offer(Address, Address),            // (founder, seller) -> PositionOffer
+}
+
+const CIDV0_LEN: usize = 46;
+const MIN_CIDV1_LEN: usize = 59;
+const MAX_CID_LEN: usize = 128;
+const REVENUE_PRECISION: i128 = 1_000_000_000_000;
+const MERKLE_LEAF_TAG: u8 = 0x00;
+const MERKLE_NODE_TAG: u8 = 0x01;
+
 // ============================================================================
 // MAIN CONTRACT
 // ============================================================================
@@ -77,6 +676,7 @@ impl DeCoV2 {
     pub fn init(
         env: Env,
         admin: Address,
+        token: Address,
         application_fee: i128,
         vc_stake_required: i128,
         min_vote_balance: i128,
@@ -88,6 +688,7 @@ impl DeCoV2 {
         
         let config = ContractConfig {
             admin,
+            token,
             application_fee,
             vc_stake_required,
             paused: false,
@@ -115,6 +716,7 @@ impl DeCoV2 {
         if admin != config.admin {
             panic!("Unauthorized: not admin");
         }
+        Self::require_not_shutdown(&env);
         
         config.paused = true;
         env.storage().instance().set(&DataKey::Config, &config);
@@ -133,13 +735,43 @@ impl DeCoV2 {
         if admin != config.admin {
             panic!("Unauthorized: not admin");
         }
+        Self::require_not_shutdown(&env);
         
         config.paused = false;
         env.storage().instance().set(&DataKey::Config, &config);
     }
     
-    /// Check if contract is paused
+    /// Pause or resume a single group of functions - only admin
+    pub fn set_paused(env: Env, admin: Address, flag: PauseFlag, paused: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::Paused(flag), &paused);
+    }
+
+    /// Check whether a group of functions is paused (globally or individually)
+    pub fn is_paused(env: Env, flag: PauseFlag) -> bool {
+        let config: ContractConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Contract not initialized");
+
+        config.paused
+            || Self::is_shutdown(env.clone())
+            || env
+                .storage()
+                .instance()
+                .get(&DataKey::Paused(flag))
+                .unwrap_or(false)
+    }
+
+    /// Check if contract is paused or shut down
     fn require_not_paused(env: &Env) {
+        if Self::is_shutdown(env.clone()) {
+            panic!("Contract is shut down");
+        }
+
         let config: ContractConfig = env
             .storage()
             .instance()
@@ -151,28 +783,331 @@ impl DeCoV2 {
         }
     }
 
-    // ========================================================================
-    // REENTRANCY GUARD
-    // ========================================================================
-    
-    fn acquire_lock(env: &Env, address: &Address) {
-        let guard_key = DataKey::ReentrancyGuard(address.clone());
-        if env.storage().instance().has(&guard_key) {
-            panic!("Reentrancy detected");
+    /// Check if a specific group of functions is paused
+    fn require_not_paused_for(env: &Env, flag: PauseFlag) {
+        Self::require_not_paused(env);
+
+        if env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused(flag))
+            .unwrap_or(false)
+        {
+            panic!("Function is paused");
         }
-        env.storage().instance().set(&guard_key, &true);
     }
-    
-    fn release_lock(env: &Env, address: &Address) {
-        let guard_key = DataKey::ReentrancyGuard(address.clone());
-        env.storage().instance().remove(&guard_key);
+
+    /// Load config and check that caller is the admin
+    fn require_admin(env: &Env, admin: &Address) -> ContractConfig {
+        let config: ContractConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Contract not initialized");
+
+        if *admin != config.admin {
+            panic!("Unauthorized: not admin");
+        }
+        Self::require_not_shutdown(env);
+
+        config
     }
 
-    // ========================================================================
-    // CHECKED MATH HELPERS
-    // ========================================================================
-    
-    fn checked_add(a: i128, b: i128) -> i128 {
+    /// Entry points that still name the token must name the escrow token
+    fn require_config_token(env: &Env, token: &Address) {
+        if *token != Self::get_config(env.clone()).token {
+            panic!("Wrong token");
+        }
+    }
+
+    /// Admin settings are frozen once the contract is shut down
+    fn require_not_shutdown(env: &Env) {
+        if Self::is_shutdown(env.clone()) {
+            panic!("Contract is shut down");
+        }
+    }
+
+    // ========================================================================
+    // EMERGENCY SHUTDOWN
+    // ========================================================================
+
+    /// Permanently shut the contract down - only admin
+    /// After shutdown investors can only recover unreleased escrow, founders
+    /// can only withdraw their already-unlocked balance and VCs can only
+    /// take back their stake; admin settings are frozen
+    pub fn emergency_shutdown(env: Env, admin: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::Shutdown, &true);
+    }
+
+    pub fn is_shutdown(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Shutdown)
+            .unwrap_or(false)
+    }
+
+    /// Investor withdraws their pro rata share of a startup's unreleased escrow
+    pub fn emergency_withdraw_investor(
+        env: Env,
+        investor: Address,
+        founder: Address,
+    ) -> i128 {
+        investor.require_auth();
+
+        if !Self::is_shutdown(env.clone()) {
+            panic!("Contract is not shut down");
+        }
+
+        let config = Self::get_config(env.clone());
+        Self::refund_escrow_share(&env, &investor, &founder, &config.token, true)
+    }
+
+    /// Pay an investor their share of a frozen round's unreleased escrow.
+    /// Refunds from rounds the founder has since reapplied past are paid
+    /// first; the current round only counts when `include_current` is set.
+    fn refund_escrow_share(
+        env: &Env,
+        investor: &Address,
+        founder: &Address,
+        xlm_token: &Address,
+        include_current: bool,
+    ) -> i128 {
+        Self::acquire_lock(env, investor);
+
+        let round = Self::refund_round(env, founder, |round, current| {
+            ((current && include_current) || Self::is_wound_down(env, round))
+                && Self::escrow_refund_owed(env, round, investor)
+        });
+        let Some(mut startup_data) = round else {
+            Self::release_lock(env, investor);
+            panic!("Nothing to withdraw");
+        };
+
+        let investment = Self::round_investment(env, &startup_data, investor);
+        let refund_key = DataKey::RefundClaimed(startup_data.application_id, investor.clone());
+
+        // Escrow is frozen, so every investor's share is computed against
+        // the same remaining balance. Escrow already ruled back in disputes
+        // is paid separately from the refund pool.
+        let escrow = Self::checked_sub(
+            Self::checked_sub(startup_data.total_allocated, startup_data.unlocked_balance),
+            Self::dispute_refund_pool(env, startup_data.application_id)
+        );
+        let refund = Self::checked_mul(escrow, investment) / startup_data.total_allocated;
+
+        env.storage().instance().set(&refund_key, &refund);
+
+        startup_data.refunded_balance = Self::checked_add(
+            startup_data.refunded_balance,
+            refund
+        );
+        Self::save_round(env, founder, &startup_data);
+
+        // The refunded round no longer earns revenue
+        Self::release_position(env, investor, founder, investment);
+
+        if refund > 0 {
+            let token_client = token::Client::new(env, xlm_token);
+            token_client.transfer(&env.current_contract_address(), investor, &refund);
+        }
+
+        Self::check_invariants(env, xlm_token);
+        Self::release_lock(env, investor);
+        refund
+    }
+
+    fn escrow_refund_owed(env: &Env, round: &StartupData, investor: &Address) -> bool {
+        Self::round_investment(env, round, investor) > 0
+            && !env
+                .storage()
+                .instance()
+                .has(&DataKey::RefundClaimed(round.application_id, investor.clone()))
+    }
+
+    fn round_investment(env: &Env, round: &StartupData, investor: &Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApplicationInvestment(round.application_id, investor.clone()))
+            .unwrap_or(0)
+    }
+
+    /// The earliest closed round the investor is `owed` from, else the
+    /// current round; `owed` is told whether the round is the current one
+    fn refund_round(
+        env: &Env,
+        founder: &Address,
+        owed: impl Fn(&StartupData, bool) -> bool,
+    ) -> Option<StartupData> {
+        Self::closed_rounds(env, founder)
+            .iter()
+            .find(|round| owed(round, false))
+            .or_else(|| {
+                let current = Self::get_startup_status(env.clone(), founder.clone())?;
+                owed(&current, true).then_some(current)
+            })
+    }
+
+    /// Write back a round found by `refund_round`
+    fn save_round(env: &Env, founder: &Address, round: &StartupData) {
+        let key = TerminationKey::ClosedRound(round.application_id);
+        if env.storage().instance().has(&key) {
+            env.storage().instance().set(&key, round);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::Startup(founder.clone()), round);
+        }
+    }
+
+    /// Keep a round's unrefunded escrow claimable once its founder reapplies
+    fn close_round(env: &Env, founder: &Address, round: &StartupData) {
+        let escrow = Self::checked_sub(
+            Self::checked_sub(round.total_allocated, round.unlocked_balance),
+            round.refunded_balance
+        );
+        if escrow <= 0 {
+            return;
+        }
+
+        env.storage()
+            .instance()
+            .set(&TerminationKey::ClosedRound(round.application_id), round);
+
+        let key = TerminationKey::ClosedRounds(founder.clone());
+        let mut closed: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        closed.push_back(round.application_id);
+        env.storage().instance().set(&key, &closed);
+    }
+
+    /// Rounds the founder reapplied past while they still held escrow
+    fn closed_rounds(env: &Env, founder: &Address) -> Vec<StartupData> {
+        let closed: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&TerminationKey::ClosedRounds(founder.clone()))
+            .unwrap_or(Vec::new(env));
+
+        let mut rounds = Vec::new(env);
+        for application_id in closed.iter() {
+            if let Some(round) = env.storage().instance().get(&TerminationKey::ClosedRound(application_id)) {
+                rounds.push_back(round);
+            }
+        }
+        rounds
+    }
+
+    /// Founder withdraws their already-unlocked balance after shutdown
+    pub fn emergency_withdraw_founder(env: Env, founder: Address) -> i128 {
+        let founder_key = Self::require_founder(&env, &founder);
+
+        if !Self::is_shutdown(env.clone()) {
+            panic!("Contract is not shut down");
+        }
+
+        let xlm_token = Self::get_config(env.clone()).token;
+
+        Self::require_no_team(&env, &founder);
+        Self::acquire_lock(&env, &founder);
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::is_wound_down(&env, &startup_data) {
+            Self::release_lock(&env, &founder);
+            panic!("Startup terminated or failed");
+        }
+
+        let claimable = Self::checked_sub(
+            startup_data.unlocked_balance,
+            startup_data.claimed_balance
+        );
+
+        if claimable <= 0 {
+            Self::release_lock(&env, &founder);
+            panic!("No funds to claim");
+        }
+
+        startup_data.claimed_balance = Self::checked_add(
+            startup_data.claimed_balance,
+            claimable
+        );
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&env.current_contract_address(), &founder_key, &claimable);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &founder);
+        claimable
+    }
+
+    /// VC takes back their stake after shutdown
+    pub fn emergency_withdraw_stake(env: Env, vc_address: Address) -> i128 {
+        vc_address.require_auth();
+
+        if !Self::is_shutdown(env.clone()) {
+            panic!("Contract is not shut down");
+        }
+
+        Self::acquire_lock(&env, &vc_address);
+
+        let mut vc_data: VCData = env
+            .storage()
+            .instance()
+            .get(&DataKey::VCData(vc_address.clone()))
+            .expect("VC not found");
+
+        let stake = vc_data.stake_amount;
+        if stake <= 0 {
+            Self::release_lock(&env, &vc_address);
+            panic!("No stake to withdraw");
+        }
+
+        vc_data.stake_amount = 0;
+        env.storage()
+            .instance()
+            .set(&DataKey::VCData(vc_address.clone()), &vc_data);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&env.current_contract_address(), &vc_address, &stake);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &vc_address);
+        stake
+    }
+
+    // ========================================================================
+    // REENTRANCY GUARD
+    // ========================================================================
+    
+    fn acquire_lock(env: &Env, address: &Address) {
+        let guard_key = DataKey::ReentrancyGuard(address.clone());
+        if env.storage().instance().has(&guard_key) {
+            panic!("Reentrancy detected");
+        }
+        env.storage().instance().set(&guard_key, &true);
+    }
+    
+    fn release_lock(env: &Env, address: &Address) {
+        let guard_key = DataKey::ReentrancyGuard(address.clone());
+        env.storage().instance().remove(&guard_key);
+    }
+
+    // ========================================================================
+    // CHECKED MATH HELPERS
+    // ========================================================================
+    
+    fn checked_add(a: i128, b: i128) -> i128 {
         a.checked_add(b).expect("Overflow in addition")
     }
     
@@ -194,19 +1129,118 @@ impl DeCoV2 {
         ipfs_cid: String,
         funding_goal: i128,
     ) {
-        founder.require_auth();
-        Self::require_not_paused(&env);
+        let founder_key = Self::require_founder(&env, &founder);
+        Self::require_not_paused_for(&env, PauseFlag::Applications);
 
-        if env.storage().instance().has(&DataKey::Startup(founder.clone())) {
-            panic!("Already applied");
+        Self::validate_cid(&ipfs_cid);
+
+        let bounds = Self::get_funding_bounds(env.clone());
+        if funding_goal <= 0
+            || funding_goal < bounds.min_funding_goal
+            || (bounds.max_funding_goal > 0 && funding_goal > bounds.max_funding_goal)
+        {
+            panic!("Funding goal out of bounds");
         }
 
+        let previous: Option<StartupData> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()));
+
+        // A founder keeps their startup id across rounds
+        let startup_id = match previous {
+            Some(previous) => {
+                Self::require_can_reapply(&env, &previous);
+                Self::close_round(&env, &founder, &previous);
+                previous.startup_id
+            }
+            None => {
+                let startup_id: u64 = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::NextStartupId)
+                    .unwrap_or(0);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::NextStartupId, &startup_id.checked_add(1).expect("Startup id overflow"));
+                env.storage()
+                    .instance()
+                    .set(&DataKey::StartupById(startup_id), &founder);
+
+                let mut all_startups: Vec<Address> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::AllStartups)
+                    .unwrap_or(Vec::new(&env));
+
+                all_startups.push_back(founder.clone());
+                env.storage().instance().set(&DataKey::AllStartups, &all_startups);
+
+                startup_id
+            }
+        };
+
+        let application_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextApplicationId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextApplicationId, &application_id.checked_add(1).expect("Application id overflow"));
+
+        let record = ApplicationRecord {
+            application_id,
+            founder: founder.clone(),
+            ipfs_cid: ipfs_cid.clone(),
+            funding_goal,
+            submitted_at: env.ledger().timestamp(),
+            closed_at: 0,
+            yes_votes: 0,
+            no_votes: 0,
+            outcome: ApplicationOutcome::Pending,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::Application(application_id), &record);
+
+        let mut applications: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FounderApplications(founder.clone()))
+            .unwrap_or(Vec::new(&env));
+        applications.push_back(application_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::FounderApplications(founder.clone()), &applications);
+
+        Self::record_metadata_revision(&env, application_id, &ipfs_cid);
+
         let config: ContractConfig = env
             .storage()
             .instance()
             .get(&DataKey::Config)
             .expect("Contract not initialized");
 
+        if config.application_fee > 0 {
+            let fee_token = Self::get_fee_token(env.clone()).expect("Fee token not set");
+            let token_client = token::Client::new(&env, &fee_token);
+            token_client.transfer(&founder_key, &env.current_contract_address(), &config.application_fee);
+
+            // Part of the fee is set aside for voters who back this startup
+            let pot = Self::checked_mul(
+                config.application_fee,
+                Self::get_reward_pool_bps(env.clone()) as i128
+            ) / 10_000;
+            if pot > 0 {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::RewardPot(application_id), &pot);
+                Self::adjust_reward_liabilities(&env, pot);
+            }
+            Self::credit_treasury(&env, &fee_token, Self::checked_sub(config.application_fee, pot));
+        }
+
         let voting_end_time = Self::checked_add(
             env.ledger().timestamp() as i128,
             (7 * 24 * 60 * 60) as i128
@@ -225,36 +1259,202 @@ impl DeCoV2 {
             milestone_count: 4,              // 4 milestones = 25% each
             current_milestone: 0,
             last_milestone_time: 0,
+            refunded_balance: 0,
+            investor_count: 0,
+            startup_id,
+            application_id,
+            community_allocation: 0,
+            community_raised: 0,
         };
 
         env.storage()
             .instance()
-            .set(&DataKey::Startup(founder.clone()), &startup_data);
+            .set(&DataKey::Startup(founder), &startup_data);
+    }
 
-        let mut all_startups: Vec<Address> = env
+    /// A new round may start once the previous application was closed, the
+    /// founder claimed what it released and the cooldown has passed.
+    /// Investor refunds stay claimable from the closed round.
+    fn require_can_reapply(env: &Env, previous: &StartupData) {
+        let record: ApplicationRecord = env
             .storage()
             .instance()
-            .get(&DataKey::AllStartups)
-            .unwrap_or(Vec::new(&env));
-        
-        all_startups.push_back(founder);
-        env.storage().instance().set(&DataKey::AllStartups, &all_startups);
+            .get(&DataKey::Application(previous.application_id))
+            .expect("Application not found");
+
+        if record.outcome != ApplicationOutcome::Rejected
+            && record.outcome != ApplicationOutcome::Completed
+            && record.outcome != ApplicationOutcome::Failed
+        {
+            panic!("Already applied");
+        }
+
+        if previous.unlocked_balance > previous.claimed_balance {
+            panic!("Unclaimed funds remain");
+        }
+
+        let cooldown = Self::get_reapply_cooldown(env.clone());
+        if env.ledger().timestamp() < record.closed_at.saturating_add(cooldown) {
+            panic!("Reapply cooldown active");
+        }
+    }
+
+    /// Move the current application to a new outcome, snapshotting its votes
+    fn set_outcome(env: &Env, startup_data: &StartupData, outcome: ApplicationOutcome) {
+        let key = DataKey::Application(startup_data.application_id);
+        let mut record: ApplicationRecord = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Application not found");
+
+        let was_open = record.outcome == ApplicationOutcome::Pending
+            || record.outcome == ApplicationOutcome::Approved;
+
+        record.outcome = outcome;
+        record.yes_votes = startup_data.yes_votes;
+        record.no_votes = startup_data.no_votes;
+
+        if was_open && outcome != ApplicationOutcome::Pending && outcome != ApplicationOutcome::Approved {
+            record.closed_at = env.ledger().timestamp();
+            Self::close_reward_pot(env, startup_data.application_id, outcome);
+        }
+
+        env.storage().instance().set(&key, &record);
+    }
+
+    fn current_outcome(env: &Env, startup_data: &StartupData) -> ApplicationOutcome {
+        env.storage()
+            .instance()
+            .get::<_, ApplicationRecord>(&DataKey::Application(startup_data.application_id))
+            .expect("Application not found")
+            .outcome
+    }
+
+    /// Terminated and failed rounds release nothing more to the founder;
+    /// their escrow goes back to investors
+    fn is_wound_down(env: &Env, startup_data: &StartupData) -> bool {
+        matches!(
+            Self::current_outcome(env, startup_data),
+            ApplicationOutcome::Terminated | ApplicationOutcome::Failed
+        )
+    }
+
+    /// Founder replaces the application's IPFS CID while voting is open.
+    /// Every revision is kept so voters can see what changed.
+    pub fn update_application_metadata(env: Env, founder: Address, ipfs_cid: String) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused_for(&env, PauseFlag::Applications);
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if startup_data.approved
+            || env.ledger().timestamp() > startup_data.voting_end_time
+            || Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Pending
+        {
+            panic!("Voting period has ended");
+        }
+
+        Self::validate_cid(&ipfs_cid);
+        Self::record_metadata_revision(&env, startup_data.application_id, &ipfs_cid);
+
+        let key = DataKey::Application(startup_data.application_id);
+        let mut record: ApplicationRecord = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Application not found");
+        record.ipfs_cid = ipfs_cid.clone();
+        env.storage().instance().set(&key, &record);
+
+        startup_data.ipfs_cid = ipfs_cid;
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder), &startup_data);
+    }
+
+    fn record_metadata_revision(env: &Env, application_id: u64, ipfs_cid: &String) {
+        let mut history: Vec<MetadataRevision> = env
+            .storage()
+            .instance()
+            .get(&DataKey::MetadataHistory(application_id))
+            .unwrap_or(Vec::new(env));
+
+        if history.iter().any(|revision| revision.ipfs_cid == *ipfs_cid) {
+            panic!("CID already uploaded");
+        }
+
+        history.push_back(MetadataRevision {
+            ipfs_cid: ipfs_cid.clone(),
+            updated_at: env.ledger().timestamp(),
+        });
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MetadataHistory(application_id), &history);
+    }
+
+    /// Accept CIDv0 (base58btc "Qm...", 46 chars) or CIDv1 (base32 "ba...")
+    fn validate_cid(ipfs_cid: &String) {
+        let len = ipfs_cid.len() as usize;
+        if !(CIDV0_LEN..=MAX_CID_LEN).contains(&len) {
+            panic!("Invalid IPFS CID");
+        }
+
+        let mut buf = [0u8; MAX_CID_LEN];
+        let cid = &mut buf[..len];
+        ipfs_cid.copy_into_slice(cid);
+
+        let valid = if cid.starts_with(b"Qm") {
+            len == CIDV0_LEN
+                && cid.iter().all(|c| {
+                    c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l')
+                })
+        } else if cid.starts_with(b"ba") {
+            len >= MIN_CIDV1_LEN
+                && cid.iter().all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c))
+        } else {
+            false
+        };
+
+        if !valid {
+            panic!("Invalid IPFS CID");
+        }
     }
 
     // ========================================================================
     // VOTING WITH SYBIL RESISTANCE
     // ========================================================================
     
+    /// `proof` is only checked when the round has a voter allowlist; pass an
+    /// empty proof otherwise or once eligibility has been recorded
     pub fn vote(
         env: Env,
         voter: Address,
         founder: Address,
         vote_yes: bool,
+        proof: Vec<BytesN<32>>,
         xlm_token: Address,
     ) {
         voter.require_auth();
-        Self::require_not_paused(&env);
+        Self::require_not_paused_for(&env, PauseFlag::Voting);
+
+        Self::require_config_token(&env, &xlm_token);
+
+        Self::record_vote(&env, &voter, &founder, vote_yes, &proof);
+    }
 
+    fn record_vote(
+        env: &Env,
+        voter: &Address,
+        founder: &Address,
+        vote_yes: bool,
+        proof: &Vec<BytesN<32>>,
+    ) {
         let config: ContractConfig = env
             .storage()
             .instance()
@@ -262,8 +1462,8 @@ impl DeCoV2 {
             .expect("Contract not initialized");
 
         // SYBIL RESISTANCE: Check voter has minimum XLM balance
-        let token_client = token::Client::new(&env, &xlm_token);
-        let voter_balance = token_client.balance(&voter);
+        let token_client = token::Client::new(env, &config.token);
+        let voter_balance = token_client.balance(voter);
         
         if voter_balance < config.min_vote_balance {
             panic!("Insufficient balance to vote - Sybil resistance");
@@ -275,18 +1475,49 @@ impl DeCoV2 {
             .get(&DataKey::Startup(founder.clone()))
             .expect("Startup not found");
 
-        if env.ledger().timestamp() > startup_data.voting_end_time {
+        if env.ledger().timestamp() > startup_data.voting_end_time
+            || Self::current_outcome(env, &startup_data) == ApplicationOutcome::Rejected
+        {
             panic!("Voting period has ended");
         }
 
-        let vote_key = DataKey::Vote(voter.clone(), founder.clone());
+        let vote_key = DataKey::ApplicationVote(startup_data.application_id, voter.clone());
         if env.storage().instance().has(&vote_key) {
             panic!("Already voted");
         }
 
+        Self::require_eligible(env, startup_data.application_id, voter, proof);
+        let separate = Self::check_conflicts(env, voter, founder);
+
         env.storage().instance().set(&vote_key, &vote_yes);
 
+        // Conflicted votes are kept out of the public tally, reputation and rewards
+        if separate {
+            let tally_key = ConflictKey::InvestorTally(startup_data.application_id);
+            let mut tally: InvestorTally = env
+                .storage()
+                .instance()
+                .get(&tally_key)
+                .unwrap_or(InvestorTally { yes_votes: 0, no_votes: 0 });
+            if vote_yes {
+                tally.yes_votes = tally.yes_votes.checked_add(1).expect("Vote overflow");
+            } else {
+                tally.no_votes = tally.no_votes.checked_add(1).expect("Vote overflow");
+            }
+            env.storage().instance().set(&tally_key, &tally);
+            return;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&RewardKey::Unsettled(startup_data.application_id, voter.clone()), &true);
+
         if vote_yes {
+            let backers_key = RewardKey::Backers(startup_data.application_id);
+            let backers: u32 = env.storage().instance().get(&backers_key).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&backers_key, &backers.checked_add(1).expect("Vote overflow"));
             startup_data.yes_votes = startup_data.yes_votes.checked_add(1).expect("Vote overflow");
         } else {
             startup_data.no_votes = startup_data.no_votes.checked_add(1).expect("Vote overflow");
@@ -294,7 +1525,392 @@ impl DeCoV2 {
 
         env.storage()
             .instance()
-            .set(&DataKey::Startup(founder), &startup_data);
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+    }
+
+    /// When the round has an allowlist, the voter must prove membership once;
+    /// the result is remembered for the rest of the round
+    fn require_eligible(env: &Env, application_id: u64, voter: &Address, proof: &Vec<BytesN<32>>) {
+        let root: BytesN<32> = match env
+            .storage()
+            .instance()
+            .get(&EligibilityKey::VoterRoot(application_id))
+        {
+            Some(root) => root,
+            None if Self::is_allowlist_required(env.clone()) => panic!("Voter allowlist not published"),
+            None => return,
+        };
+
+        let eligible_key = EligibilityKey::Eligible(application_id, voter.clone());
+        if env.storage().persistent().has(&eligible_key) {
+            return;
+        }
+
+        // Sorted-pair hashing, so proofs carry no left/right flags. Leaves and
+        // nodes get distinct tag bytes so a node can't pass as a leaf.
+        let mut leaf = Bytes::from_array(env, &[MERKLE_LEAF_TAG]);
+        leaf.append(&voter.clone().to_xdr(env));
+        let mut node: BytesN<32> = env.crypto().sha256(&leaf).into();
+        for sibling in proof.iter() {
+            let (first, second) = if node <= sibling { (node, sibling) } else { (sibling, node) };
+            let mut pair = Bytes::from_array(env, &[MERKLE_NODE_TAG]);
+            pair.append(&Bytes::from(first));
+            pair.append(&Bytes::from(second));
+            node = env.crypto().sha256(&pair).into();
+        }
+
+        if node != root {
+            panic!("Voter not on allowlist");
+        }
+
+        env.storage().persistent().set(&eligible_key, &true);
+    }
+
+    /// Refuse conflicted voters with a typed error. Returns whether the vote
+    /// belongs in the separate investor tally.
+    fn check_conflicts(env: &Env, voter: &Address, founder: &Address) -> bool {
+        let rules = Self::get_conflict_rules(env.clone());
+
+        if rules.bar_team {
+            if voter == founder || *voter == Self::founder_key(env, founder) {
+                panic_with_error!(env, VoteError::FounderVote);
+            }
+            if let Some(team) = Self::get_team(env.clone(), founder.clone()) {
+                if team.members.contains(voter) {
+                    panic_with_error!(env, VoteError::TeamMemberVote);
+                }
+            }
+        }
+
+        if env
+            .storage()
+            .instance()
+            .has(&ConflictKey::RelatedParty(founder.clone(), voter.clone()))
+        {
+            panic_with_error!(env, VoteError::RelatedParty);
+        }
+
+        if Self::get_vc_investment(env.clone(), voter.clone(), founder.clone()) > 0 {
+            match rules.investor_policy {
+                InvestorVotePolicy::Allowed => {}
+                InvestorVotePolicy::Barred => panic_with_error!(env, VoteError::InvestorVote),
+                InvestorVotePolicy::Separate => return true,
+            }
+        }
+
+        false
+    }
+
+    // ========================================================================
+    // SIGNED VOTES (RELAYED)
+    // ========================================================================
+
+    /// Voter registers the ed25519 key they will sign relayed votes with.
+    /// A key can belong to only one voter.
+    pub fn register_vote_key(env: Env, voter: Address, public_key: BytesN<32>) {
+        voter.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Voting);
+
+        let owner_key = SignedVoteKey::KeyOwner(public_key.clone());
+        let owner: Option<Address> = env.storage().persistent().get(&owner_key);
+        if owner.is_some_and(|owner| owner != voter) {
+            panic!("Vote key already registered");
+        }
+
+        let voter_key = SignedVoteKey::VoteKey(voter.clone());
+        let previous: Option<BytesN<32>> = env.storage().persistent().get(&voter_key);
+        if let Some(previous) = previous {
+            env.storage()
+                .persistent()
+                .remove(&SignedVoteKey::KeyOwner(previous));
+        }
+
+        env.storage().persistent().set(&voter_key, &public_key);
+        env.storage().persistent().set(&owner_key, &voter);
+    }
+
+    /// Relayer submits a batch of signed votes in one transaction. Every
+    /// signature must verify and every nonce must be the voter's next one,
+    /// otherwise the whole batch is rejected. The usual voting rules,
+    /// including the minimum balance, still apply to each voter.
+    pub fn submit_signed_votes(env: Env, batch: Vec<SignedVote>) -> u32 {
+        Self::require_not_paused_for(&env, PauseFlag::Voting);
+
+        for signed in batch.iter() {
+            let public_key: BytesN<32> = env
+                .storage()
+                .persistent()
+                .get(&SignedVoteKey::VoteKey(signed.voter.clone()))
+                .expect("Vote key not registered");
+
+            let nonce_key = SignedVoteKey::VoteNonce(signed.voter.clone());
+            let nonce: u64 = env.storage().persistent().get(&nonce_key).unwrap_or(0);
+            if signed.nonce != nonce {
+                panic!("Invalid nonce");
+            }
+
+            let message = Self::signed_vote_message(
+                env.clone(),
+                signed.voter.clone(),
+                signed.founder.clone(),
+                signed.vote_yes,
+                signed.nonce
+            );
+            env.crypto().ed25519_verify(&public_key, &message, &signed.signature);
+
+            env.storage()
+                .persistent()
+                .set(&nonce_key, &nonce.checked_add(1).expect("Nonce overflow"));
+
+            Self::record_vote(
+                &env,
+                &signed.voter,
+                &signed.founder,
+                signed.vote_yes,
+                &signed.proof
+            );
+        }
+
+        batch.len()
+    }
+
+    /// The bytes a voter signs for a relayed vote
+    pub fn signed_vote_message(
+        env: Env,
+        voter: Address,
+        founder: Address,
+        vote_yes: bool,
+        nonce: u64,
+    ) -> Bytes {
+        (env.current_contract_address(), voter, founder, vote_yes, nonce).to_xdr(&env)
+    }
+
+    pub fn get_vote_key(env: Env, voter: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&SignedVoteKey::VoteKey(voter))
+    }
+
+    pub fn get_vote_nonce(env: Env, voter: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&SignedVoteKey::VoteNonce(voter))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // VOTER REPUTATION & REWARDS
+    // ========================================================================
+
+    /// Voter claims their accumulated rewards, paid in the fee token
+    pub fn claim_voter_rewards(env: Env, voter: Address) -> i128 {
+        voter.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Voting);
+        Self::acquire_lock(&env, &voter);
+
+        let rewards = Self::voter_rewards(env.clone(), voter.clone());
+        if rewards <= 0 {
+            Self::release_lock(&env, &voter);
+            panic!("No rewards to claim");
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VoterRewards(voter.clone()));
+        Self::adjust_reward_liabilities(&env, -rewards);
+
+        let fee_token = Self::get_fee_token(env.clone()).expect("Fee token not set");
+        let token_client = token::Client::new(&env, &fee_token);
+        token_client.transfer(&env.current_contract_address(), &voter, &rewards);
+
+        Self::check_invariants(&env, &fee_token);
+        Self::release_lock(&env, &voter);
+        rewards
+    }
+
+    /// Split a closed application's reward pot into equal shares for its
+    /// backers if the startup completed; otherwise it goes to the treasury.
+    /// Voters then settle one at a time with `settle_vote`.
+    fn close_reward_pot(env: &Env, application_id: u64, outcome: ApplicationOutcome) {
+        let pot: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardPot(application_id))
+            .unwrap_or(0);
+        if pot <= 0 {
+            return;
+        }
+        env.storage()
+            .instance()
+            .remove(&DataKey::RewardPot(application_id));
+
+        let backers: u32 = env
+            .storage()
+            .instance()
+            .get(&RewardKey::Backers(application_id))
+            .unwrap_or(0);
+
+        let mut owed = 0i128;
+        if outcome == ApplicationOutcome::Completed && backers > 0 {
+            let share = pot / backers as i128;
+            env.storage()
+                .instance()
+                .set(&RewardKey::BackerShare(application_id), &share);
+            owed = Self::checked_mul(share, backers as i128);
+        }
+
+        // Whatever is not owed to voters falls back to the treasury
+        let unpaid = Self::checked_sub(pot, owed);
+        Self::adjust_reward_liabilities(env, -unpaid);
+        let fee_token = Self::get_fee_token(env.clone()).expect("Fee token not set");
+        Self::credit_treasury(env, &fee_token, unpaid);
+    }
+
+    /// Update a voter's reputation for a closed application and credit
+    /// their share of its reward pot if they backed a completed startup.
+    /// Anyone may settle a vote; each vote settles once.
+    pub fn settle_vote(env: Env, voter: Address, application_id: u64) {
+        Self::require_not_paused_for(&env, PauseFlag::Voting);
+
+        let record: ApplicationRecord = env
+            .storage()
+            .instance()
+            .get(&DataKey::Application(application_id))
+            .expect("Application not found");
+
+        if record.outcome == ApplicationOutcome::Pending
+            || record.outcome == ApplicationOutcome::Approved
+        {
+            panic!("Application still open");
+        }
+
+        let unsettled_key = RewardKey::Unsettled(application_id, voter.clone());
+        if !env.storage().persistent().has(&unsettled_key) {
+            panic!("Nothing to settle");
+        }
+        env.storage().persistent().remove(&unsettled_key);
+
+        // Rejected applications never got a chance to prove anyone right
+        if record.outcome == ApplicationOutcome::Rejected {
+            return;
+        }
+
+        let vote_yes: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::ApplicationVote(application_id, voter.clone()))
+            .unwrap_or(false);
+        let success = record.outcome == ApplicationOutcome::Completed;
+
+        let mut stats = Self::get_voter_stats(env.clone(), voter.clone());
+        if vote_yes == success {
+            stats.reputation = stats.reputation.saturating_add(1);
+            stats.correct_calls = stats.correct_calls.saturating_add(1);
+        } else {
+            stats.reputation = stats.reputation.saturating_sub(1);
+            stats.incorrect_calls = stats.incorrect_calls.saturating_add(1);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::VoterStats(voter.clone()), &stats);
+
+        let share: i128 = env
+            .storage()
+            .instance()
+            .get(&RewardKey::BackerShare(application_id))
+            .unwrap_or(0);
+        if vote_yes && share > 0 {
+            let rewards = Self::voter_rewards(env.clone(), voter.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::VoterRewards(voter), &Self::checked_add(rewards, share));
+        }
+    }
+
+    fn adjust_reward_liabilities(env: &Env, delta: i128) {
+        let liabilities: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardLiabilities)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::RewardLiabilities, &Self::checked_add(liabilities, delta));
+    }
+
+    /// Voter reward pots and unclaimed rewards held in `xlm_token`
+    fn reward_liabilities(env: &Env, xlm_token: &Address) -> i128 {
+        if Self::get_fee_token(env.clone()).as_ref() != Some(xlm_token) {
+            return 0;
+        }
+
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardLiabilities)
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // VC DUE-DILIGENCE REVIEWS
+    // ========================================================================
+
+    /// Staked VC publishes a scored review of the founder's current application
+    pub fn submit_review(
+        env: Env,
+        vc_address: Address,
+        founder: Address,
+        score: u32,
+        report_cid: String,
+    ) {
+        vc_address.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Applications);
+
+        let vc_data: VCData = env
+            .storage()
+            .instance()
+            .get(&DataKey::VCData(vc_address.clone()))
+            .expect("Not a verified VC");
+
+        if vc_data.stake_amount <= 0 {
+            panic!("Not a verified VC");
+        }
+
+        if score > 100 {
+            panic!("Score must be 0-100");
+        }
+
+        Self::validate_cid(&report_cid);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        let outcome = Self::current_outcome(&env, &startup_data);
+        if outcome != ApplicationOutcome::Pending && outcome != ApplicationOutcome::Approved {
+            panic!("Application closed");
+        }
+
+        let review_key = DataKey::Review(startup_data.application_id, vc_address.clone());
+        if env.storage().instance().has(&review_key) {
+            panic!("Already reviewed");
+        }
+
+        let review = ReviewRecord {
+            reviewer: vc_address.clone(),
+            score,
+            report_cid,
+            submitted_at: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&review_key, &review);
+
+        let reviewers_key = DataKey::Reviewers(startup_data.application_id);
+        let mut reviewers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&reviewers_key)
+            .unwrap_or(Vec::new(&env));
+        reviewers.push_back(vc_address);
+        env.storage().instance().set(&reviewers_key, &reviewers);
     }
 
     // ========================================================================
@@ -321,14 +1937,73 @@ impl DeCoV2 {
             .get(&DataKey::Startup(founder.clone()))
             .expect("Startup not found");
 
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Pending {
+            panic!("Application already decided");
+        }
+
+        let summary = Self::get_review_summary(env.clone(), founder.clone());
+        if summary.review_count < Self::get_min_reviews(env.clone()) {
+            panic!("Not enough VC reviews");
+        }
+
         startup_data.approved = true;
         startup_data.last_milestone_time = env.ledger().timestamp();
+        Self::set_outcome(&env, &startup_data, ApplicationOutcome::Approved);
 
         env.storage()
             .instance()
             .set(&DataKey::Startup(founder), &startup_data);
     }
 
+    /// Admin marks an approved startup as having failed its milestones
+    pub fn mark_failed(env: Env, admin: Address, founder: Address) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        Self::wind_down(&env, &founder, startup_data, ApplicationOutcome::Failed);
+    }
+
+    /// Close a round early. Unlocked money the founder never claimed goes
+    /// back into escrow, so investor refunds cover everything still held.
+    fn wind_down(env: &Env, founder: &Address, mut startup_data: StartupData, outcome: ApplicationOutcome) {
+        startup_data.unlocked_balance = startup_data.claimed_balance;
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+
+        Self::set_outcome(env, &startup_data, outcome);
+    }
+
+    /// Admin rejects a pending application; the founder may reapply later
+    pub fn reject_application(env: Env, admin: Address, founder: Address) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Pending {
+            panic!("Application already decided");
+        }
+
+        Self::set_outcome(&env, &startup_data, ApplicationOutcome::Rejected);
+    }
+
     // ========================================================================
     // VC STAKING (WITH REENTRANCY GUARD)
     // ========================================================================
@@ -340,7 +2015,8 @@ impl DeCoV2 {
         xlm_token: Address,
     ) {
         vc_address.require_auth();
-        Self::require_not_paused(&env);
+        Self::require_not_paused_for(&env, PauseFlag::Staking);
+        Self::require_config_token(&env, &xlm_token);
         Self::acquire_lock(&env, &vc_address);
 
         if env.storage().instance().has(&DataKey::VCData(vc_address.clone())) {
@@ -366,6 +2042,8 @@ impl DeCoV2 {
             company_name,
             stake_amount: config.vc_stake_required,
             total_invested: 0,
+            metadata_cid: None,
+            verified: false,
         };
 
         env.storage()
@@ -381,102 +2059,175 @@ impl DeCoV2 {
         all_vcs.push_back(vc_address.clone());
         env.storage().instance().set(&DataKey::AllVCs, &all_vcs);
 
+        Self::check_invariants(&env, &xlm_token);
         Self::release_lock(&env, &vc_address);
     }
 
-    // ========================================================================
-    // VC INVESTMENT (WITH REENTRANCY GUARD & CHECKED MATH)
-    // ========================================================================
-    
-    pub fn vc_invest(
-        env: Env,
-        vc_address: Address,
-        founder: Address,
-        amount: i128,
-        xlm_token: Address,
-    ) {
+    /// VC adds to their stake, e.g. to reach a higher tier
+    pub fn top_up_stake(env: Env, vc_address: Address, amount: i128) {
         vc_address.require_auth();
-        Self::require_not_paused(&env);
-        Self::acquire_lock(&env, &vc_address);
+        Self::require_not_paused_for(&env, PauseFlag::Staking);
 
-        if !env.storage().instance().has(&DataKey::VCData(vc_address.clone())) {
-            Self::release_lock(&env, &vc_address);
-            panic!("Not a verified VC");
+        if amount <= 0 {
+            panic!("Amount must be positive");
         }
 
-        let mut startup_data: StartupData = env
+        Self::acquire_lock(&env, &vc_address);
+
+        let mut vc_data: VCData = env
             .storage()
             .instance()
-            .get(&DataKey::Startup(founder.clone()))
-            .expect("Startup not found");
-
-        if !startup_data.approved {
-            Self::release_lock(&env, &vc_address);
-            panic!("Startup not approved");
-        }
+            .get(&DataKey::VCData(vc_address.clone()))
+            .expect("Not a VC");
 
+        let xlm_token = Self::get_config(env.clone()).token;
         let token_client = token::Client::new(&env, &xlm_token);
         token_client.transfer(&vc_address, &env.current_contract_address(), &amount);
 
-        // CHECKED MATH
-        startup_data.total_allocated = Self::checked_add(startup_data.total_allocated, amount);
-        
-        // Funds go to escrow, not immediately unlocked
-        // Will be unlocked via milestone system
-
+        vc_data.stake_amount = Self::checked_add(vc_data.stake_amount, amount);
         env.storage()
             .instance()
-            .set(&DataKey::Startup(founder.clone()), &startup_data);
+            .set(&DataKey::VCData(vc_address.clone()), &vc_data);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &vc_address);
+    }
+
+    /// VC updates their public profile
+    pub fn update_vc_profile(
+        env: Env,
+        vc_address: Address,
+        company_name: String,
+        metadata_cid: Option<String>,
+    ) {
+        vc_address.require_auth();
+        Self::require_not_paused(&env);
 
         let mut vc_data: VCData = env
             .storage()
             .instance()
             .get(&DataKey::VCData(vc_address.clone()))
-            .expect("VC data not found");
+            .expect("Not a VC");
+
+        if let Some(cid) = &metadata_cid {
+            Self::validate_cid(cid);
+        }
 
-        vc_data.total_invested = Self::checked_add(vc_data.total_invested, amount);
+        vc_data.company_name = company_name;
+        vc_data.metadata_cid = metadata_cid;
         env.storage()
             .instance()
-            .set(&DataKey::VCData(vc_address.clone()), &vc_data);
+            .set(&DataKey::VCData(vc_address), &vc_data);
+    }
 
-        let investment_key = DataKey::Investment(vc_address.clone(), founder.clone());
-        let current_investment: i128 = env
+    /// Admin or a registered KYC attester marks a VC as verified
+    pub fn set_vc_verified(env: Env, attester: Address, vc_address: Address, verified: bool) {
+        attester.require_auth();
+
+        let config: ContractConfig = env
             .storage()
             .instance()
-            .get(&investment_key)
-            .unwrap_or(0);
+            .get(&DataKey::Config)
+            .expect("Contract not initialized");
 
-        env.storage()
+        if attester != config.admin && !Self::is_kyc_attester(env.clone(), attester) {
+            panic!("Unauthorized: not an attester");
+        }
+        Self::require_not_shutdown(&env);
+
+        let mut vc_data: VCData = env
+            .storage()
             .instance()
-            .set(&investment_key, &Self::checked_add(current_investment, amount));
+            .get(&DataKey::VCData(vc_address.clone()))
+            .expect("Not a VC");
 
-        Self::release_lock(&env, &vc_address);
+        vc_data.verified = verified;
+        env.storage()
+            .instance()
+            .set(&DataKey::VCData(vc_address), &vc_data);
+    }
+
+    fn tier_for_stake(env: &Env, stake_amount: i128) -> VCTier {
+        let tiers = Self::get_tier_config(env.clone());
+        if stake_amount >= tiers.lead_stake {
+            VCTier::Lead
+        } else if stake_amount >= tiers.seed_fund_stake {
+            VCTier::SeedFund
+        } else {
+            VCTier::Angel
+        }
+    }
+
+    fn max_ticket_for_tier(env: &Env, tier: VCTier) -> i128 {
+        let tiers = Self::get_tier_config(env.clone());
+        match tier {
+            VCTier::Angel => tiers.angel_max_ticket,
+            VCTier::SeedFund => tiers.seed_fund_max_ticket,
+            VCTier::Lead => tiers.lead_max_ticket,
+        }
     }
 
     // ========================================================================
-    // MILESTONE-BASED FUND RELEASE (25% INCREMENTS)
+    // VC INVESTMENT (WITH REENTRANCY GUARD & CHECKED MATH)
     // ========================================================================
     
-    /// Unlock next milestone (25% of total allocated)
-    /// Can be triggered by admin or automatically after time interval
-    pub fn unlock_milestone(
+    /// Returns the amount actually invested; anything above the startup's
+    /// funding cap is never taken from the VC
+    pub fn vc_invest(
         env: Env,
-        admin: Address,
+        vc_address: Address,
         founder: Address,
-    ) {
-        admin.require_auth();
-        Self::require_not_paused(&env);
+        amount: i128,
+        xlm_token: Address,
+    ) -> i128 {
+        vc_address.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+        Self::require_config_token(&env, &xlm_token);
 
-        let config: ContractConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::Config)
-            .expect("Contract not initialized");
-        
-        if admin != config.admin {
-            panic!("Unauthorized: not admin");
+        if amount <= 0 {
+            panic!("Amount must be positive");
         }
 
+        Self::acquire_lock(&env, &vc_address);
+
+        if !env.storage().instance().has(&DataKey::VCData(vc_address.clone())) {
+            Self::release_lock(&env, &vc_address);
+            panic!("Not a verified VC");
+        }
+
+        let tier = Self::get_vc_tier(env.clone(), vc_address.clone());
+        let tier_max_ticket = Self::max_ticket_for_tier(&env, tier);
+
+        let (accepted, invested) = Self::record_investment(
+            &env,
+            &vc_address,
+            &founder,
+            amount,
+            tier_max_ticket,
+            false,
+        );
+
+        let shares = Self::shares_for(&env, &founder, invested);
+        Self::mint_shares(&env, &founder, &vc_address, shares);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &vc_address);
+        accepted
+    }
+
+    /// Shared escrow path for every kind of investor. Takes the investor's
+    /// tokens, applies the funding cap, ticket limits and platform fee, and
+    /// records the position. Community money only fills the community
+    /// allocation; everyone else is kept out of it.
+    /// Returns (amount taken, amount escrowed).
+    fn record_investment(
+        env: &Env,
+        investor: &Address,
+        founder: &Address,
+        amount: i128,
+        extra_max_ticket: i128,
+        community: bool,
+    ) -> (i128, i128) {
         let mut startup_data: StartupData = env
             .storage()
             .instance()
@@ -484,106 +2235,3163 @@ impl DeCoV2 {
             .expect("Startup not found");
 
         if !startup_data.approved {
+            Self::release_lock(env, investor);
             panic!("Startup not approved");
         }
 
-        if startup_data.current_milestone >= startup_data.milestone_count {
-            panic!("All milestones already unlocked");
+        if Self::current_outcome(env, &startup_data) != ApplicationOutcome::Approved {
+            Self::release_lock(env, investor);
+            panic!("Startup is not raising");
         }
 
-        // Check if enough time has passed since last milestone
-        let time_since_last = Self::checked_sub(
-            env.ledger().timestamp() as i128,
-            startup_data.last_milestone_time as i128
+        let limits = Self::get_investment_limits(env.clone());
+        let round_key = DataKey::ApplicationInvestment(startup_data.application_id, investor.clone());
+        let round_investment: i128 = env
+            .storage()
+            .instance()
+            .get(&round_key)
+            .unwrap_or(0);
+
+        // Accept at most what is left under the funding cap
+        let funding_cap = Self::checked_mul(
+            startup_data.funding_goal,
+            Self::checked_add(10_000, limits.overfunding_bps as i128)
+        ) / 10_000;
+        let community_reserved = Self::checked_sub(
+            startup_data.community_allocation,
+            startup_data.community_raised
         );
+        let remaining = Self::checked_sub(funding_cap, startup_data.total_allocated);
+        let remaining = if community {
+            if community_reserved < remaining { community_reserved } else { remaining }
+        } else {
+            Self::checked_sub(remaining, community_reserved)
+        };
+
+        if remaining <= 0 {
+            Self::release_lock(env, investor);
+            panic!("Funding cap reached");
+        }
+
+        let amount = if amount > remaining { remaining } else { amount };
+
+        // Platform take-rate goes to the treasury, the rest is escrowed
+        let fee = Self::checked_mul(amount, Self::get_platform_fee_bps(env.clone()) as i128) / 10_000;
+        let invested = Self::checked_sub(amount, fee);
+
+        if community {
+            let contribution_key = DataKey::CommunityContribution(startup_data.application_id, investor.clone());
+            let contributed: i128 = env
+                .storage()
+                .instance()
+                .get(&contribution_key)
+                .unwrap_or(0);
+            let user_cap = Self::get_community_user_cap(env.clone());
+
+            // Counted net of the platform fee, like community_raised
+            if user_cap > 0 && Self::checked_add(contributed, invested) > user_cap {
+                Self::release_lock(env, investor);
+                panic!("Above community cap");
+            }
+
+            env.storage()
+                .instance()
+                .set(&contribution_key, &Self::checked_add(contributed, invested));
+        } else {
+            // The final ticket that closes the round may be below the minimum
+            if amount < limits.min_ticket && amount < remaining {
+                Self::release_lock(env, investor);
+                panic!("Below minimum ticket");
+            }
+
+            if limits.max_ticket > 0
+                && Self::checked_add(round_investment, amount) > limits.max_ticket
+            {
+                Self::release_lock(env, investor);
+                panic!("Above maximum ticket");
+            }
+
+            if extra_max_ticket > 0
+                && Self::checked_add(round_investment, amount) > extra_max_ticket
+            {
+                Self::release_lock(env, investor);
+                panic!("Above tier maximum ticket");
+            }
+        }
+
+        if round_investment == 0 && !community {
+            if limits.max_investors > 0 && startup_data.investor_count >= limits.max_investors {
+                Self::release_lock(env, investor);
+                panic!("Investor limit reached");
+            }
+            startup_data.investor_count = startup_data.investor_count.checked_add(1).expect("Investor overflow");
+        }
+
+        let xlm_token = &Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(env, xlm_token);
+        token_client.transfer(investor, &env.current_contract_address(), &amount);
+
+        Self::credit_treasury(env, xlm_token, fee);
+
+        // CHECKED MATH
+        startup_data.total_allocated = Self::checked_add(startup_data.total_allocated, invested);
+        if community {
+            startup_data.community_raised = Self::checked_add(startup_data.community_raised, invested);
+        }
         
-        if time_since_last < config.milestone_interval as i128 {
-            panic!("Milestone interval not reached");
+        // Funds go to escrow, not immediately unlocked
+        // Will be unlocked via milestone system
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+
+        if let Some(mut vc_data) = env
+            .storage()
+            .instance()
+            .get::<_, VCData>(&DataKey::VCData(investor.clone()))
+        {
+            vc_data.total_invested = Self::checked_add(vc_data.total_invested, invested);
+            env.storage()
+                .instance()
+                .set(&DataKey::VCData(investor.clone()), &vc_data);
         }
 
-        // Calculate 25% of total allocated
-        let milestone_amount = Self::checked_mul(
-            startup_data.total_allocated,
-            25
-        ) / 100;
+        env.storage()
+            .instance()
+            .set(&round_key, &Self::checked_add(round_investment, invested));
 
-        startup_data.unlocked_balance = Self::checked_add(
-            startup_data.unlocked_balance,
-            milestone_amount
+        Self::adjust_position(env, investor, founder, invested);
+
+        (amount, invested)
+    }
+
+    // ========================================================================
+    // COMMUNITY ROUND (RETAIL CROWDFUNDING)
+    // ========================================================================
+
+    /// Founder reserves part of the funding cap for community investors
+    pub fn set_community_allocation(env: Env, founder: Address, allocation: i128) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not raising");
+        }
+
+        let limits = Self::get_investment_limits(env.clone());
+        let funding_cap = Self::checked_mul(
+            startup_data.funding_goal,
+            Self::checked_add(10_000, limits.overfunding_bps as i128)
+        ) / 10_000;
+        let raised_elsewhere = Self::checked_sub(
+            startup_data.total_allocated,
+            startup_data.community_raised
         );
-        
-        startup_data.current_milestone = startup_data.current_milestone.checked_add(1).expect("Milestone overflow");
-        startup_data.last_milestone_time = env.ledger().timestamp();
 
+        if allocation < startup_data.community_raised
+            || allocation > Self::checked_sub(funding_cap, raised_elsewhere)
+        {
+            panic!("Invalid community allocation");
+        }
+
+        startup_data.community_allocation = allocation;
         env.storage()
             .instance()
             .set(&DataKey::Startup(founder), &startup_data);
     }
 
+    /// Non-VC backs a startup from the community allocation. The money is
+    /// escrowed and released by milestones exactly like VC money.
+    pub fn community_invest(
+        env: Env,
+        investor: Address,
+        founder: Address,
+        amount: i128,
+    ) -> i128 {
+        investor.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        // VCs are held to ticket and investor limits through vc_invest
+        if env.storage().instance().has(&DataKey::VCData(investor.clone())) {
+            panic!("VCs invest through vc_invest");
+        }
+
+        Self::acquire_lock(&env, &investor);
+
+        let (accepted, invested) = Self::record_investment(
+            &env,
+            &investor,
+            &founder,
+            amount,
+            0,
+            true,
+        );
+
+        let shares = Self::shares_for(&env, &founder, invested);
+        Self::mint_shares(&env, &founder, &investor, shares);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &investor);
+        accepted
+    }
+
     // ========================================================================
-    // CLAIM FUNDS (WITH REENTRANCY GUARD & CHECKED MATH)
+    // SYNDICATES (LEAD INVESTOR)
     // ========================================================================
-    
-    pub fn claim_funds(env: Env, founder: Address, xlm_token: Address) {
-        founder.require_auth();
-        Self::require_not_paused(&env);
-        Self::acquire_lock(&env, &founder);
 
-        let mut startup_data: StartupData = env
+    /// Lead-tier VC opens a syndicate for a startup
+    pub fn open_syndicate(
+        env: Env,
+        lead: Address,
+        founder: Address,
+        carry_bps: u32,
+        min_ticket: i128,
+    ) {
+        lead.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        if Self::get_vc_tier(env.clone(), lead.clone()) != VCTier::Lead {
+            panic!("Only Lead-tier VCs can open a syndicate");
+        }
+
+        if carry_bps > 10_000 || min_ticket < 0 {
+            panic!("Invalid syndicate terms");
+        }
+
+        let startup_data: StartupData = env
             .storage()
             .instance()
             .get(&DataKey::Startup(founder.clone()))
             .expect("Startup not found");
 
-        let claimable = Self::checked_sub(
-            startup_data.unlocked_balance,
-            startup_data.claimed_balance
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not raising");
+        }
+
+        let key = DataKey::Syndicate(founder.clone(), lead.clone());
+        if env.storage().instance().has(&key) {
+            panic!("Syndicate already exists");
+        }
+
+        let syndicate = Syndicate {
+            lead: lead.clone(),
+            carry_bps,
+            min_ticket,
+            total_raised: 0,
+            member_count: 0,
+            open: true,
+        };
+        env.storage().instance().set(&key, &syndicate);
+
+        let mut leads: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SyndicateLeads(founder.clone()))
+            .unwrap_or(Vec::new(&env));
+        leads.push_back(lead);
+        env.storage()
+            .instance()
+            .set(&DataKey::SyndicateLeads(founder), &leads);
+    }
+
+    /// Lead stops accepting new syndicate members
+    pub fn close_syndicate(env: Env, lead: Address, founder: Address) {
+        lead.require_auth();
+
+        let key = DataKey::Syndicate(founder, lead);
+        let mut syndicate: Syndicate = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Syndicate not found");
+
+        syndicate.open = false;
+        env.storage().instance().set(&key, &syndicate);
+    }
+
+    /// Anyone, staked VC or not, invests in a startup through a syndicate.
+    /// The position is the member's; the lead earns carry on it.
+    pub fn join_syndicate(
+        env: Env,
+        member: Address,
+        founder: Address,
+        lead: Address,
+        amount: i128,
+    ) -> i128 {
+        member.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        if member == lead {
+            panic!("Lead invests directly");
+        }
+
+        Self::acquire_lock(&env, &member);
+
+        let key = DataKey::Syndicate(founder.clone(), lead.clone());
+        let mut syndicate: Syndicate = env
+            .storage()
+            .instance()
+            .get(&key)
+            .expect("Syndicate not found");
+
+        if !syndicate.open {
+            Self::release_lock(&env, &member);
+            panic!("Syndicate closed");
+        }
+
+        // A member backs a startup through at most one syndicate
+        let member_key = DataKey::SyndicateMember(founder.clone(), member.clone());
+        match env.storage().instance().get::<_, Address>(&member_key) {
+            Some(existing) if existing != lead => {
+                Self::release_lock(&env, &member);
+                panic!("Already in another syndicate");
+            }
+            Some(_) => {}
+            None => {
+                env.storage().instance().set(&member_key, &lead);
+                syndicate.member_count = syndicate.member_count.checked_add(1).expect("Member overflow");
+            }
+        }
+
+        let (accepted, invested) = Self::record_investment(
+            &env,
+            &member,
+            &founder,
+            amount,
+            0,
+            false,
         );
 
-        if claimable <= 0 {
-            Self::release_lock(&env, &founder);
-            panic!("No funds to claim");
+        // The funding cap may have cut the ticket down
+        if accepted < syndicate.min_ticket {
+            Self::release_lock(&env, &member);
+            panic!("Below syndicate minimum ticket");
         }
 
-        // Update state BEFORE transfer (checks-effects-interactions pattern)
-        startup_data.claimed_balance = Self::checked_add(
-            startup_data.claimed_balance,
-            claimable
+        let contribution_key = DataKey::SyndicateContribution(founder.clone(), member.clone());
+        let contribution: i128 = env
+            .storage()
+            .instance()
+            .get(&contribution_key)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&contribution_key, &Self::checked_add(contribution, invested));
+
+        syndicate.total_raised = Self::checked_add(syndicate.total_raised, invested);
+        env.storage().instance().set(&key, &syndicate);
+
+        // Carry on the member's shares goes to the lead
+        let shares = Self::shares_for(&env, &founder, invested);
+        let carry = Self::checked_mul(shares, syndicate.carry_bps as i128) / 10_000;
+        Self::mint_shares(&env, &founder, &member, Self::checked_sub(shares, carry));
+        Self::mint_shares(&env, &founder, &lead, carry);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &member);
+        accepted
+    }
+
+    // ========================================================================
+    // REVENUE SHARING (INVESTOR PAYBACK)
+    // ========================================================================
+
+    /// Founder sets the payback cap before any revenue is deposited
+    pub fn set_payback_cap(env: Env, founder: Address, payback_cap_bps: u32) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if !env.storage().instance().has(&DataKey::Startup(founder.clone())) {
+            panic!("Startup not found");
+        }
+
+        let mut pool = Self::load_revenue_pool(&env, &founder);
+        if pool.total_deposited > 0 {
+            panic!("Payback cap locked after first deposit");
+        }
+
+        pool.payback_cap_bps = payback_cap_bps;
+        env.storage()
+            .instance()
+            .set(&RevenueKey::Pool(founder), &pool);
+    }
+
+    /// Founder shares revenue with investors. Returns the amount accepted;
+    /// nothing beyond the payback cap is taken.
+    pub fn deposit_revenue(env: Env, founder: Address, amount: i128, xlm_token: Address) -> i128 {
+        let founder_key = Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        let mut pool = Self::load_revenue_pool(&env, &founder);
+
+        if pool.total_units <= 0 {
+            panic!("No investors to pay");
+        }
+
+        match &pool.token {
+            Some(token) if *token != xlm_token => panic!("Wrong revenue token"),
+            _ => pool.token = Some(xlm_token.clone()),
+        }
+
+        let mut amount = amount;
+        if pool.payback_cap_bps > 0 {
+            let cap = Self::checked_mul(pool.total_units, pool.payback_cap_bps as i128) / 10_000;
+            let remaining = Self::checked_sub(cap, pool.total_deposited);
+            if remaining <= 0 {
+                panic!("Payback cap reached");
+            }
+            if amount > remaining {
+                amount = remaining;
+            }
+        }
+
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&founder_key, &env.current_contract_address(), &amount);
+
+        pool.acc_per_unit = Self::checked_add(
+            pool.acc_per_unit,
+            Self::checked_mul(amount, REVENUE_PRECISION) / pool.total_units
         );
+        pool.total_deposited = Self::checked_add(pool.total_deposited, amount);
 
         env.storage()
             .instance()
-            .set(&DataKey::Startup(founder.clone()), &startup_data);
+            .set(&RevenueKey::Pool(founder), &pool);
+
+        Self::check_invariants(&env, &xlm_token);
+        amount
+    }
+
+    /// Investor withdraws their share of deposited revenue. Syndicate members
+    /// pay their lead's carry out of it.
+    pub fn claim_revenue(env: Env, investor: Address, founder: Address) -> i128 {
+        investor.require_auth();
+        // Revenue already deposited stays claimable after shutdown
+        if !Self::is_shutdown(env.clone()) {
+            Self::require_not_paused_for(&env, PauseFlag::Claiming);
+        }
+        Self::acquire_lock(&env, &investor);
+
+        Self::settle_revenue(&env, &investor, &founder);
+
+        let owed_key = RevenueKey::Owed(founder.clone(), investor.clone());
+        let owed: i128 = env.storage().instance().get(&owed_key).unwrap_or(0);
+
+        if owed <= 0 {
+            Self::release_lock(&env, &investor);
+            panic!("No revenue to claim");
+        }
+
+        env.storage().instance().remove(&owed_key);
+
+        let mut payout = owed;
+        if let Some(lead) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::SyndicateMember(founder.clone(), investor.clone()))
+        {
+            let syndicate: Syndicate = env
+                .storage()
+                .instance()
+                .get(&DataKey::Syndicate(founder.clone(), lead.clone()))
+                .expect("Syndicate not found");
+
+            let carry = Self::checked_mul(owed, syndicate.carry_bps as i128) / 10_000;
+            let lead_key = RevenueKey::Owed(founder.clone(), lead);
+            let lead_owed: i128 = env.storage().instance().get(&lead_key).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&lead_key, &Self::checked_add(lead_owed, carry));
+            payout = Self::checked_sub(owed, carry);
+        }
+
+        let mut pool = Self::load_revenue_pool(&env, &founder);
+        pool.total_claimed = Self::checked_add(pool.total_claimed, payout);
+        env.storage()
+            .instance()
+            .set(&RevenueKey::Pool(founder), &pool);
+
+        let xlm_token = pool.token.expect("No revenue deposited");
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&env.current_contract_address(), &investor, &payout);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &investor);
+        payout
+    }
+
+    /// Change an investor's lifetime position, settling their revenue first
+    fn adjust_position(env: &Env, investor: &Address, founder: &Address, delta: i128) {
+        Self::settle_revenue(env, investor, founder);
+
+        let investment_key = DataKey::Investment(investor.clone(), founder.clone());
+        let current_investment: i128 = env
+            .storage()
+            .instance()
+            .get(&investment_key)
+            .unwrap_or(0);
+        let units = Self::checked_add(current_investment, delta);
+
+        env.storage().instance().set(&investment_key, &units);
+
+        if current_investment == 0 && units > 0 {
+            Self::index_position(env, investor, founder);
+        }
+
+        let mut pool = Self::load_revenue_pool(env, founder);
+        pool.total_units = Self::checked_add(pool.total_units, delta);
+        env.storage()
+            .instance()
+            .set(&RevenueKey::Pool(founder.clone()), &pool);
+
+        env.storage().instance().set(
+            &RevenueKey::Debt(founder.clone(), investor.clone()),
+            &(Self::checked_mul(units, pool.acc_per_unit) / REVENUE_PRECISION),
+        );
+    }
+
+    /// Shrink a position by up to `amount` after money was returned to the investor
+    fn release_position(env: &Env, investor: &Address, founder: &Address, amount: i128) {
+        let position: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Investment(investor.clone(), founder.clone()))
+            .unwrap_or(0);
+
+        let amount = amount.min(position);
+        if amount > 0 {
+            Self::adjust_position(env, investor, founder, -amount);
+        }
+    }
+
+    /// Move revenue accrued since the last settlement into the owed balance
+    fn settle_revenue(env: &Env, investor: &Address, founder: &Address) {
+        let pending = Self::pending_revenue(env.clone(), investor.clone(), founder.clone());
+        let units: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Investment(investor.clone(), founder.clone()))
+            .unwrap_or(0);
+        let pool = Self::load_revenue_pool(env, founder);
+
+        env.storage().instance().set(
+            &RevenueKey::Debt(founder.clone(), investor.clone()),
+            &(Self::checked_mul(units, pool.acc_per_unit) / REVENUE_PRECISION),
+        );
+
+        if pending > 0 {
+            let owed_key = RevenueKey::Owed(founder.clone(), investor.clone());
+            let owed: i128 = env.storage().instance().get(&owed_key).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&owed_key, &Self::checked_add(owed, pending));
+        }
+    }
+
+    /// List a newly opened position in both indexes, unless it was listed before
+    fn index_position(env: &Env, investor: &Address, founder: &Address) {
+        Self::append_to_index(
+            env,
+            IndexKey::InPortfolio(investor.clone(), founder.clone()),
+            IndexKey::PortfolioLen(investor.clone()),
+            |i| IndexKey::Portfolio(investor.clone(), i),
+            founder,
+        );
+        Self::append_to_index(
+            env,
+            IndexKey::InInvestors(founder.clone(), investor.clone()),
+            IndexKey::InvestorsLen(founder.clone()),
+            |i| IndexKey::Investors(founder.clone(), i),
+            investor,
+        );
+    }
+
+    fn append_to_index(
+        env: &Env,
+        listed_key: IndexKey,
+        len_key: IndexKey,
+        entry_key: impl Fn(u32) -> IndexKey,
+        entry: &Address,
+    ) {
+        if env.storage().persistent().has(&listed_key) {
+            return;
+        }
+
+        let len: u32 = env.storage().persistent().get(&len_key).unwrap_or(0);
+        env.storage().persistent().set(&entry_key(len), entry);
+        env.storage()
+            .persistent()
+            .set(&len_key, &len.checked_add(1).expect("Index overflow"));
+        env.storage().persistent().set(&listed_key, &true);
+    }
+
+    fn load_revenue_pool(env: &Env, founder: &Address) -> RevenuePool {
+        env.storage()
+            .instance()
+            .get(&RevenueKey::Pool(founder.clone()))
+            .unwrap_or(RevenuePool {
+                token: None,
+                acc_per_unit: 0,
+                total_units: 0,
+                total_deposited: 0,
+                total_claimed: 0,
+                payback_cap_bps: 0,
+            })
+    }
+
+    // ========================================================================
+    // SECONDARY MARKET (POSITION TRANSFERS)
+    // ========================================================================
+
+    /// Founder designates who gets first refusal on position sales. `None`
+    /// lets positions trade freely.
+    pub fn set_right_of_first_refusal(
+        env: Env,
+        founder: Address,
+        holder: Option<Address>,
+        window: u64,
+    ) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if !env.storage().instance().has(&DataKey::Startup(founder.clone())) {
+            panic!("Startup not found");
+        }
+
+        match holder {
+            Some(holder) => {
+                if window == 0 {
+                    panic!("Window must be positive");
+                }
+                env.storage().instance().set(
+                    &PositionKey::Rofr(founder),
+                    &RightOfFirstRefusal { holder, window },
+                );
+            }
+            None => env.storage().instance().remove(&PositionKey::Rofr(founder)),
+        }
+    }
+
+    /// Seller announces a sale, starting the ROFR holder's window to match it
+    pub fn offer_position(env: Env, seller: Address, founder: Address, amount: i128, price: i128) {
+        seller.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        if !env.storage().instance().has(&PositionKey::Rofr(founder.clone())) {
+            panic!("No right of first refusal");
+        }
+        let position = Self::require_position(&env, &seller, &founder, amount, price);
+
+        // Lets the ROFR holder take the matching shares without the seller
+        // signing again. Only `exercise_rofr` spends it, so it may outlive the
+        // window; the token keeps allowances one ledger past their expiry.
+        if let Some(terms) = Self::get_share_terms(env.clone(), founder.clone()) {
+            let share_client = token::Client::new(&env, &terms.token);
+            let shares = Self::checked_mul(share_client.balance(&seller), amount) / position;
+            let expiration = env.ledger().sequence() + env.storage().max_ttl() - 1;
+            share_client.approve(&seller, &env.current_contract_address(), &shares, &expiration);
+        }
+
+        env.storage().instance().set(
+            &PositionKey::Offer(founder, seller),
+            &PositionOffer {
+                amount,
+                price,
+                offered_at: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// ROFR holder buys an offered position on the seller's terms
+    pub fn exercise_rofr(env: Env, founder: Address, seller: Address) {
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        let rofr: RightOfFirstRefusal = env
+            .storage()
+            .instance()
+            .get(&PositionKey::Rofr(founder.clone()))
+            .expect("No right of first refusal");
+        rofr.holder.require_auth();
+
+        let offer_key = PositionKey::Offer(founder.clone(), seller.clone());
+        let offer: PositionOffer = env
+            .storage()
+            .instance()
+            .get(&offer_key)
+            .expect("No offer");
+
+        if env.ledger().timestamp() > offer.offered_at.saturating_add(rofr.window) {
+            panic!("Refusal window closed");
+        }
+
+        env.storage().instance().remove(&offer_key);
+        Self::move_position(&env, &seller, &rofr.holder, &founder, offer.amount, offer.price, true);
+    }
+
+    /// Sell part of a position to `to`, who pays `price` in the same call.
+    /// Under a ROFR the sale must match an offer whose window has lapsed.
+    pub fn transfer_position(
+        env: Env,
+        from: Address,
+        to: Address,
+        founder: Address,
+        amount: i128,
+        price: i128,
+    ) {
+        from.require_auth();
+        to.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Investing);
+
+        if let Some(rofr) = env
+            .storage()
+            .instance()
+            .get::<_, RightOfFirstRefusal>(&PositionKey::Rofr(founder.clone()))
+        {
+            if to != rofr.holder {
+                let offer_key = PositionKey::Offer(founder.clone(), from.clone());
+                let offer: PositionOffer = env
+                    .storage()
+                    .instance()
+                    .get(&offer_key)
+                    .expect("Position must be offered first");
+
+                if env.ledger().timestamp() <= offer.offered_at.saturating_add(rofr.window) {
+                    panic!("Refusal window still open");
+                }
+                if amount > offer.amount || price < offer.price {
+                    panic!("Terms worse than offer");
+                }
+
+                env.storage().instance().remove(&offer_key);
+            }
+        }
+
+        Self::move_position(&env, &from, &to, &founder, amount, price, false);
+    }
+
+    fn require_position(env: &Env, holder: &Address, founder: &Address, amount: i128, price: i128) -> i128 {
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if price < 0 {
+            panic!("Price cannot be negative");
+        }
+
+        let position: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Investment(holder.clone(), founder.clone()))
+            .unwrap_or(0);
+        if position < amount {
+            panic!("Insufficient position");
+        }
+
+        position
+    }
+
+    /// Settle payment and move `amount` of position, along with the matching
+    /// share of the seller's current-round escrow, shares and syndicate
+    /// contribution
+    fn move_position(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        founder: &Address,
+        amount: i128,
+        price: i128,
+        by_offer: bool,
+    ) {
+        if from == to {
+            panic!("Cannot transfer to self");
+        }
+
+        Self::require_no_investor_vote(env, founder);
+        Self::require_no_open_dispute(env, founder);
+
+        // Refund rights only follow the position within the current round
+        let closed_refund = Self::closed_rounds(env, founder).iter().any(|round| {
+            Self::dispute_refund_owed(env, &round, from) > 0
+                || (Self::is_wound_down(env, &round) && Self::escrow_refund_owed(env, &round, from))
+        });
+        if closed_refund {
+            panic!("Claim refunds from closed rounds first");
+        }
+
+        let position = Self::require_position(env, from, founder, amount, price);
+
+        Self::acquire_lock(env, from);
+
+        let xlm_token = &Self::get_config(env.clone()).token;
+        if price > 0 {
+            let token_client = token::Client::new(env, xlm_token);
+            token_client.transfer(to, from, &price);
+        }
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        // Refund rights for the open round follow the position pro rata
+        let from_round_key = DataKey::ApplicationInvestment(startup_data.application_id, from.clone());
+        let to_round_key = DataKey::ApplicationInvestment(startup_data.application_id, to.clone());
+        let from_round: i128 = env.storage().instance().get(&from_round_key).unwrap_or(0);
+        let to_round: i128 = env.storage().instance().get(&to_round_key).unwrap_or(0);
+        let refunded = env
+            .storage()
+            .instance()
+            .has(&DataKey::RefundClaimed(startup_data.application_id, from.clone()));
+        let moved_round = if refunded || Self::dispute_refund_pool(env, startup_data.application_id) > 0 {
+            0
+        } else {
+            Self::checked_mul(from_round, amount) / position
+        };
+
+        if moved_round > 0 {
+            let from_remaining = Self::checked_sub(from_round, moved_round);
+            if from_remaining == 0 {
+                startup_data.investor_count = startup_data.investor_count.saturating_sub(1);
+            }
+            if to_round == 0 {
+                let limits = Self::get_investment_limits(env.clone());
+                if limits.max_investors > 0 && startup_data.investor_count >= limits.max_investors {
+                    Self::release_lock(env, from);
+                    panic!("Investor limit reached");
+                }
+                startup_data.investor_count = startup_data.investor_count.checked_add(1).expect("Investor overflow");
+            }
+
+            env.storage().instance().set(&from_round_key, &from_remaining);
+            env.storage()
+                .instance()
+                .set(&to_round_key, &Self::checked_add(to_round, moved_round));
+            env.storage()
+                .instance()
+                .set(&DataKey::Startup(founder.clone()), &startup_data);
+        }
+
+        Self::move_syndicate_contribution(env, from, to, founder, amount, position);
+
+        let from_shares = Self::get_shares(env.clone(), founder.clone(), from.clone());
+        let moved_shares = Self::checked_mul(from_shares, amount) / position;
+        if moved_shares > 0 {
+            Self::move_shares(env, founder, from, to, moved_shares, by_offer);
+        }
+
+        Self::adjust_position(env, from, founder, -amount);
+        Self::adjust_position(env, to, founder, amount);
+
+        for (holder, delta) in [(from, -amount), (to, amount)] {
+            if let Some(mut vc_data) = env
+                .storage()
+                .instance()
+                .get::<_, VCData>(&DataKey::VCData(holder.clone()))
+            {
+                vc_data.total_invested = Self::checked_add(vc_data.total_invested, delta);
+                env.storage()
+                    .instance()
+                    .set(&DataKey::VCData(holder.clone()), &vc_data);
+            }
+        }
+
+        Self::check_invariants(env, xlm_token);
+        Self::release_lock(env, from);
+    }
+
+    /// A syndicated position keeps paying carry to its lead: a buyer with no
+    /// position joins the seller's syndicate, and the lead may buy back.
+    /// Otherwise buyer and seller must be in the same syndicate (or none).
+    fn move_syndicate_contribution(
+        env: &Env,
+        from: &Address,
+        to: &Address,
+        founder: &Address,
+        amount: i128,
+        position: i128,
+    ) {
+        let from_lead: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SyndicateMember(founder.clone(), from.clone()));
+        let to_lead: Option<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::SyndicateMember(founder.clone(), to.clone()));
+
+        let lead = match from_lead {
+            Some(lead) => lead,
+            None if to_lead.is_none() => return,
+            None => {
+                Self::release_lock(env, from);
+                panic!("Syndicate membership mismatch");
+            }
+        };
+
+        let joins = to_lead.is_none() && *to != lead;
+        if joins {
+            let to_position: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::Investment(to.clone(), founder.clone()))
+                .unwrap_or(0);
+            if to_position > 0 {
+                Self::release_lock(env, from);
+                panic!("Syndicate membership mismatch");
+            }
+
+            let syndicate_key = DataKey::Syndicate(founder.clone(), lead.clone());
+            let mut syndicate: Syndicate = env
+                .storage()
+                .instance()
+                .get(&syndicate_key)
+                .expect("Syndicate not found");
+            syndicate.member_count = syndicate.member_count.checked_add(1).expect("Member overflow");
+            env.storage().instance().set(&syndicate_key, &syndicate);
+            env.storage()
+                .instance()
+                .set(&DataKey::SyndicateMember(founder.clone(), to.clone()), &lead);
+        } else if to_lead.as_ref() != Some(&lead) && *to != lead {
+            Self::release_lock(env, from);
+            panic!("Syndicate membership mismatch");
+        }
+
+        let from_key = DataKey::SyndicateContribution(founder.clone(), from.clone());
+        let contribution: i128 = env.storage().instance().get(&from_key).unwrap_or(0);
+        let moved = Self::checked_mul(contribution, amount) / position;
+        env.storage()
+            .instance()
+            .set(&from_key, &Self::checked_sub(contribution, moved));
+
+        if *to != lead {
+            let to_key = DataKey::SyndicateContribution(founder.clone(), to.clone());
+            let to_contribution: i128 = env.storage().instance().get(&to_key).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&to_key, &Self::checked_add(to_contribution, moved));
+        }
+    }
+
+    pub fn get_right_of_first_refusal(env: Env, founder: Address) -> Option<RightOfFirstRefusal> {
+        env.storage().instance().get(&PositionKey::Rofr(founder))
+    }
+
+    pub fn get_position_offer(env: Env, founder: Address, seller: Address) -> Option<PositionOffer> {
+        env.storage().instance().get(&PositionKey::Offer(founder, seller))
+    }
+
+    // ========================================================================
+    // EQUITY SHARES (PER-STARTUP SHARE TOKEN)
+    // ========================================================================
+
+    /// Founder sets the valuation investors buy in at and mints their own shares
+    /// in `share_token`, a Stellar asset whose admin has been handed to this
+    /// contract. Terms can be replaced until investors hold shares.
+    pub fn set_share_terms(
+        env: Env,
+        founder: Address,
+        pre_money_valuation: i128,
+        founder_shares: i128,
+        share_token: Address,
+    ) {
+        let founder_key = Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if pre_money_valuation <= 0 || founder_shares <= 0 {
+            panic!("Invalid share terms");
+        }
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if !startup_data.approved {
+            panic!("Startup not approved");
+        }
+
+        if startup_data.total_allocated > 0 {
+            panic!("Share terms locked after first investment");
+        }
+
+        if token::StellarAssetClient::new(&env, &share_token).admin() != env.current_contract_address() {
+            panic!("Share token not administered by contract");
+        }
+
+        // Replace any founder shares minted under previous terms
+        if let Some(previous) = Self::get_share_terms(env.clone(), founder.clone()) {
+            if previous.total_shares != previous.founder_shares {
+                panic!("Share terms locked once investors hold shares");
+            }
+            token::Client::new(&env, &previous.token).burn(&founder_key, &previous.founder_shares);
+        }
+
+        let terms = ShareTerms {
+            pre_money_valuation,
+            founder_shares,
+            total_shares: 0,
+            token: share_token,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::ShareTerms(founder.clone()), &terms);
+
+        Self::mint_shares(&env, &founder, &founder_key, founder_shares);
+    }
+
+    /// Transfer shares of a startup between holders, listing the recipient in
+    /// the cap table. Plain transfers of the share token work as well.
+    pub fn transfer_shares(
+        env: Env,
+        from: Address,
+        to: Address,
+        founder: Address,
+        amount: i128,
+    ) {
+        from.require_auth();
+        Self::require_not_paused(&env);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        Self::move_shares(&env, &founder, &from, &to, amount, false);
+    }
+
+    /// `by_offer` spends the allowance granted by `offer_position` instead
+    /// of needing `from` to sign
+    fn move_shares(env: &Env, founder: &Address, from: &Address, to: &Address, amount: i128, by_offer: bool) {
+        let terms: ShareTerms = env
+            .storage()
+            .instance()
+            .get(&DataKey::ShareTerms(founder.clone()))
+            .expect("Share terms not set");
+
+        let share_client = token::Client::new(env, &terms.token);
+        if by_offer {
+            share_client.transfer_from(&env.current_contract_address(), from, to, &amount);
+        } else {
+            share_client.transfer(from, to, &amount);
+        }
+        Self::add_share_holder(env, founder, to);
+    }
+
+    /// Shares bought by `invested` at the founder's valuation, if terms were set
+    fn shares_for(env: &Env, founder: &Address, invested: i128) -> i128 {
+        match env
+            .storage()
+            .instance()
+            .get::<_, ShareTerms>(&DataKey::ShareTerms(founder.clone()))
+        {
+            Some(terms) => Self::checked_mul(invested, terms.founder_shares) / terms.pre_money_valuation,
+            None => 0,
+        }
+    }
+
+    fn mint_shares(env: &Env, founder: &Address, holder: &Address, shares: i128) {
+        if shares <= 0 {
+            return;
+        }
+
+        let mut terms: ShareTerms = env
+            .storage()
+            .instance()
+            .get(&DataKey::ShareTerms(founder.clone()))
+            .expect("Share terms not set");
+
+        token::StellarAssetClient::new(env, &terms.token).mint(holder, &shares);
+        Self::add_share_holder(env, founder, holder);
+
+        terms.total_shares = Self::checked_add(terms.total_shares, shares);
+        env.storage()
+            .instance()
+            .set(&DataKey::ShareTerms(founder.clone()), &terms);
+    }
+
+    fn add_share_holder(env: &Env, founder: &Address, holder: &Address) {
+        Self::append_to_index(
+            env,
+            IndexKey::InCapTable(founder.clone(), holder.clone()),
+            IndexKey::CapTableLen(founder.clone()),
+            |i| IndexKey::CapTable(founder.clone(), i),
+            holder,
+        );
+    }
+
+    // ========================================================================
+    // MILESTONE-BASED FUND RELEASE (25% INCREMENTS)
+    // ========================================================================
+    
+    /// Unlock next milestone (25% of total allocated)
+    /// Can be triggered by admin or automatically after time interval
+    pub fn unlock_milestone(
+        env: Env,
+        admin: Address,
+        founder: Address,
+    ) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+
+        let config: ContractConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Contract not initialized");
+        
+        if admin != config.admin {
+            panic!("Unauthorized: not admin");
+        }
+
+        let startup_data = Self::require_unlockable(&env, &founder);
+
+        if env.storage().instance().has(&OracleKey::Kpi(
+            startup_data.application_id,
+            startup_data.current_milestone,
+        )) {
+            panic!("Milestone requires KPI attestation");
+        }
+
+        // Check if enough time has passed since last milestone
+        let time_since_last = Self::checked_sub(
+            env.ledger().timestamp() as i128,
+            startup_data.last_milestone_time as i128
+        );
+        
+        if time_since_last < config.milestone_interval as i128 {
+            panic!("Milestone interval not reached");
+        }
+
+        Self::release_milestone(&env, &founder, startup_data);
+    }
+
+    fn require_unlockable(env: &Env, founder: &Address) -> StartupData {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if !startup_data.approved {
+            panic!("Startup not approved");
+        }
+
+        if Self::is_wound_down(env, &startup_data) {
+            panic!("Startup terminated or failed");
+        }
+
+        Self::require_no_open_dispute(env, founder);
+
+        if startup_data.current_milestone >= startup_data.milestone_count {
+            panic!("All milestones already unlocked");
+        }
+
+        startup_data
+    }
+
+    fn release_milestone(env: &Env, founder: &Address, mut startup_data: StartupData) {
+        // Calculate 25% of total allocated, never more than what is left
+        // after dispute refunds; the last milestone takes the remainder
+        let milestone_amount = Self::milestone_tranche(env, &startup_data);
+        env.storage().instance().set(
+            &DeadlineKey::Tranche(startup_data.application_id, startup_data.current_milestone),
+            &milestone_amount,
+        );
+
+        startup_data.unlocked_balance = Self::checked_add(
+            startup_data.unlocked_balance,
+            milestone_amount
+        );
+        
+        startup_data.current_milestone = startup_data.current_milestone.checked_add(1).expect("Milestone overflow");
+        startup_data.last_milestone_time = env.ledger().timestamp();
+
+        if startup_data.current_milestone == startup_data.milestone_count {
+            Self::set_outcome(env, &startup_data, ApplicationOutcome::Completed);
+        }
+
+        // The next milestone starts a fresh deadline
+        env.storage()
+            .instance()
+            .remove(&DeadlineKey::AtRisk(startup_data.application_id));
+        env.storage()
+            .instance()
+            .remove(&DeadlineKey::Request(startup_data.application_id));
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+    }
+
+    // ========================================================================
+    // ORACLE KPI MILESTONES
+    // ========================================================================
+
+    /// Founder ties a milestone of the current round to an oracle-attested
+    /// KPI. Must be set before the first investment.
+    pub fn set_kpi_milestone(
+        env: Env,
+        founder: Address,
+        milestone: u32,
+        kpi: Symbol,
+        threshold: i128,
+        oracle: Address,
+    ) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        if startup_data.total_allocated > 0 {
+            panic!("KPI milestones locked after first investment");
+        }
+        if milestone >= startup_data.milestone_count {
+            panic!("Invalid milestone");
+        }
+        if threshold <= 0 {
+            panic!("Threshold must be positive");
+        }
+        if !Self::is_oracle(env.clone(), oracle.clone()) {
+            panic!("Oracle not registered");
+        }
+
+        env.storage().instance().set(
+            &OracleKey::Kpi(startup_data.application_id, milestone),
+            &KpiMilestone { kpi, threshold, oracle },
+        );
+    }
+
+    /// Oracle reports a KPI for the founder's current milestone. The
+    /// milestone unlocks as soon as a value meets its threshold.
+    pub fn submit_kpi_attestation(
+        env: Env,
+        oracle: Address,
+        founder: Address,
+        kpi: Symbol,
+        value: i128,
+    ) -> bool {
+        oracle.require_auth();
+        Self::require_not_paused(&env);
+
+        if !Self::is_oracle(env.clone(), oracle.clone()) {
+            panic!("Oracle not registered");
+        }
+
+        let startup_data = Self::require_unlockable(&env, &founder);
+        let milestone = startup_data.current_milestone;
+
+        if startup_data.total_allocated <= 0 {
+            panic!("Nothing invested");
+        }
+
+        // KPI milestones still respect the minimum time between unlocks
+        let config = Self::get_config(env.clone());
+        if env.ledger().timestamp() < startup_data.last_milestone_time.saturating_add(config.milestone_interval) {
+            panic!("Milestone interval not reached");
+        }
+
+        let target: KpiMilestone = env
+            .storage()
+            .instance()
+            .get(&OracleKey::Kpi(startup_data.application_id, milestone))
+            .expect("Milestone has no KPI");
+
+        if target.oracle != oracle || target.kpi != kpi {
+            panic!("Attestation does not match milestone");
+        }
+
+        env.storage().instance().set(
+            &OracleKey::Attestation(startup_data.application_id, milestone),
+            &KpiAttestation {
+                value,
+                attested_at: env.ledger().timestamp(),
+            },
+        );
+
+        let met = value >= target.threshold;
+        if met {
+            Self::release_milestone(&env, &founder, startup_data);
+        }
+
+        met
+    }
+
+    pub fn get_kpi_milestone(env: Env, founder: Address, milestone: u32) -> Option<KpiMilestone> {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))?;
+
+        env.storage()
+            .instance()
+            .get(&OracleKey::Kpi(startup_data.application_id, milestone))
+    }
+
+    pub fn get_kpi_attestation(env: Env, founder: Address, milestone: u32) -> Option<KpiAttestation> {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))?;
+
+        env.storage()
+            .instance()
+            .get(&OracleKey::Attestation(startup_data.application_id, milestone))
+    }
+
+    // ========================================================================
+    // MILESTONE DEADLINES
+    // ========================================================================
+
+    /// Apply the configured penalty once the current milestone is past its
+    /// deadline and grace period. Anyone may call; each milestone is
+    /// penalized at most once.
+    pub fn enforce_milestone_deadline(env: Env, founder: Address) -> MissedMilestonePenalty {
+        Self::require_not_paused(&env);
+
+        let rules = Self::get_deadline_rules(env.clone());
+        if rules.deadline == 0 {
+            panic!("Milestone deadlines disabled");
+        }
+
+        let startup_data = Self::require_unlockable(&env, &founder);
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        let milestone = startup_data.current_milestone;
+        let penalized_key = DeadlineKey::Penalized(startup_data.application_id, milestone);
+        if env.storage().instance().has(&penalized_key) {
+            panic!("Penalty already applied");
+        }
+
+        let deadline = Self::get_milestone_deadline(env.clone(), founder.clone());
+        if env.ledger().timestamp() <= deadline.saturating_add(rules.grace_period) {
+            panic!("Milestone deadline not passed");
+        }
+
+        match rules.penalty {
+            MissedMilestonePenalty::PartialReturn => {
+                let returned = Self::checked_mul(
+                    Self::milestone_tranche(&env, &startup_data),
+                    rules.return_bps as i128
+                ) / 10_000;
+                let pool = Self::dispute_refund_pool(&env, startup_data.application_id);
+                env.storage().instance().set(
+                    &DisputeKey::RefundPool(startup_data.application_id),
+                    &Self::checked_add(pool, returned),
+                );
+            }
+            MissedMilestonePenalty::InvestorVote => {
+                let proposal_key = TerminationKey::Proposal(startup_data.application_id);
+                if !env.storage().instance().has(&proposal_key) {
+                    let now = env.ledger().timestamp();
+                    let voting_period = Self::get_termination_rules(env.clone()).voting_period;
+                    env.storage().instance().set(
+                        &proposal_key,
+                        &TerminationProposal {
+                            proposer: env.current_contract_address(),
+                            created_at: now,
+                            voting_end: now.saturating_add(voting_period),
+                            votes_for: 0,
+                            votes_against: 0,
+                        },
+                    );
+                }
+            }
+            MissedMilestonePenalty::AtRisk => {
+                env.storage()
+                    .instance()
+                    .set(&DeadlineKey::AtRisk(startup_data.application_id), &true);
+            }
+        }
+
+        env.storage().instance().set(&penalized_key, &rules.penalty);
+        rules.penalty
+    }
+
+    /// Founder asks investors for more time on the current milestone
+    pub fn request_extension(env: Env, founder: Address, extra_time: u64) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if extra_time == 0 {
+            panic!("Extension must be positive");
+        }
+
+        let startup_data = Self::require_unlockable(&env, &founder);
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        // An expired request may be replaced by a new one
+        let request_key = DeadlineKey::Request(startup_data.application_id);
+        if Self::open_extension_request(&env, startup_data.application_id).is_some() {
+            panic!("Extension already requested");
+        }
+
+        let now = env.ledger().timestamp();
+        env.storage().instance().set(
+            &request_key,
+            &ExtensionRequest {
+                extra_time,
+                requested_at: now,
+                expires_at: now.saturating_add(Self::get_termination_rules(env.clone()).voting_period),
+                votes_for: 0,
+                votes_against: 0,
+            },
+        );
+    }
+
+    /// Investor votes on the open extension request. A majority of the
+    /// round's investment either way settles it immediately.
+    pub fn vote_extension(env: Env, investor: Address, founder: Address, approve: bool) {
+        investor.require_auth();
+        Self::require_not_paused(&env);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        let request_key = DeadlineKey::Request(startup_data.application_id);
+        let mut request: ExtensionRequest = env
+            .storage()
+            .instance()
+            .get(&request_key)
+            .expect("No extension requested");
+
+        if env.ledger().timestamp() > request.expires_at {
+            panic!("Extension request expired");
+        }
+
+        let vote_key = DeadlineKey::ExtensionVote(startup_data.application_id, investor.clone());
+        if env.storage().instance().get::<_, u64>(&vote_key) == Some(request.requested_at) {
+            panic!("Already voted");
+        }
+
+        let weight = Self::termination_weight(&env, &startup_data, &investor);
+        if approve {
+            request.votes_for = Self::checked_add(request.votes_for, weight);
+        } else {
+            request.votes_against = Self::checked_add(request.votes_against, weight);
+        }
+        env.storage().instance().set(&vote_key, &request.requested_at);
+
+        let majority = startup_data.total_allocated / 2;
+        if request.votes_for > majority {
+            let extension_key = DeadlineKey::Extension(
+                startup_data.application_id,
+                startup_data.current_milestone,
+            );
+            let granted: u64 = env.storage().instance().get(&extension_key).unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&extension_key, &granted.saturating_add(request.extra_time));
+            env.storage().instance().remove(&request_key);
+        } else if request.votes_against >= Self::checked_sub(startup_data.total_allocated, majority) {
+            env.storage().instance().remove(&request_key);
+        } else {
+            env.storage().instance().set(&request_key, &request);
+        }
+    }
+
+    /// When the current milestone is due, including approved extensions.
+    /// Zero if deadlines are disabled.
+    pub fn get_milestone_deadline(env: Env, founder: Address) -> u64 {
+        let rules = Self::get_deadline_rules(env.clone());
+        if rules.deadline == 0 {
+            return 0;
+        }
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        let extension: u64 = env
+            .storage()
+            .instance()
+            .get(&DeadlineKey::Extension(
+                startup_data.application_id,
+                startup_data.current_milestone,
+            ))
+            .unwrap_or(0);
+
+        startup_data
+            .last_milestone_time
+            .saturating_add(rules.deadline)
+            .saturating_add(extension)
+    }
+
+    pub fn get_extension_request(env: Env, founder: Address) -> Option<ExtensionRequest> {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))?;
+
+        env.storage()
+            .instance()
+            .get(&DeadlineKey::Request(startup_data.application_id))
+    }
+
+    pub fn is_at_risk(env: Env, founder: Address) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, StartupData>(&DataKey::Startup(founder))
+            .map(|startup_data| {
+                env.storage()
+                    .instance()
+                    .has(&DeadlineKey::AtRisk(startup_data.application_id))
+            })
+            .unwrap_or(false)
+    }
+
+    // ========================================================================
+    // CLAIM FUNDS (WITH REENTRANCY GUARD & CHECKED MATH)
+    // ========================================================================
+    
+    pub fn claim_funds(env: Env, founder: Address, xlm_token: Address) {
+        let founder_key = Self::require_founder(&env, &founder);
+        Self::require_not_paused_for(&env, PauseFlag::Claiming);
+        Self::require_config_token(&env, &xlm_token);
+        Self::require_no_team(&env, &founder);
+
+        Self::pay_claim(&env, &founder, None, &founder_key, None);
+    }
+
+    /// Founder claims part of the unlocked balance, optionally straight to a
+    /// vendor. `purpose_cid` points at the invoice or justification.
+    pub fn claim_amount(
+        env: Env,
+        founder: Address,
+        amount: i128,
+        recipient: Address,
+        purpose_cid: String,
+    ) -> i128 {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused_for(&env, PauseFlag::Claiming);
+        Self::require_no_team(&env, &founder);
+        Self::validate_cid(&purpose_cid);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        Self::pay_claim(&env, &founder, Some(amount), &recipient, Some(purpose_cid))
+    }
+
+    /// Team-owned version of `claim_amount`, approved by a threshold of members
+    pub fn claim_amount_team(
+        env: Env,
+        founder: Address,
+        signers: Vec<Address>,
+        amount: i128,
+        recipient: Address,
+        purpose_cid: String,
+    ) -> i128 {
+        Self::require_not_paused_for(&env, PauseFlag::Claiming);
+
+        let team = Self::get_team(env.clone(), founder.clone()).expect("Team not set");
+        Self::require_team_approval(&team, &signers);
+        Self::validate_cid(&purpose_cid);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        Self::pay_claim(&env, &founder, Some(amount), &recipient, Some(purpose_cid))
+    }
+
+    /// Pay `amount` (or everything claimable) of the unlocked balance
+    fn pay_claim(
+        env: &Env,
+        founder: &Address,
+        amount: Option<i128>,
+        recipient: &Address,
+        purpose_cid: Option<String>,
+    ) -> i128 {
+        Self::acquire_lock(env, founder);
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::is_wound_down(env, &startup_data) {
+            Self::release_lock(env, founder);
+            panic!("Startup terminated or failed");
+        }
+
+        if env.storage().instance().has(&DisputeKey::Open(founder.clone())) {
+            Self::release_lock(env, founder);
+            panic!("Dispute open");
+        }
+
+        let claimable = Self::checked_sub(
+            startup_data.unlocked_balance,
+            startup_data.claimed_balance
+        );
+
+        if claimable <= 0 {
+            Self::release_lock(env, founder);
+            panic!("No funds to claim");
+        }
+
+        let amount = amount.unwrap_or(claimable);
+        if amount > claimable {
+            Self::release_lock(env, founder);
+            panic!("Amount exceeds unlocked balance");
+        }
+
+        // Update state BEFORE transfer (checks-effects-interactions pattern)
+        startup_data.claimed_balance = Self::checked_add(
+            startup_data.claimed_balance,
+            amount
+        );
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+
+        Self::log_spending(env, founder, &startup_data, recipient, amount, purpose_cid);
+
+        // Transfer after state update
+        let xlm_token = &Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(env, xlm_token);
+        token_client.transfer(&env.current_contract_address(), recipient, &amount);
+
+        Self::check_invariants(env, xlm_token);
+        Self::release_lock(env, founder);
+        amount
+    }
+
+    fn log_spending(
+        env: &Env,
+        founder: &Address,
+        startup_data: &StartupData,
+        recipient: &Address,
+        amount: i128,
+        purpose_cid: Option<String>,
+    ) {
+        // One persistent entry per record, so the log can grow without
+        // bloating instance storage
+        let count = Self::get_spending_count(env.clone(), founder.clone());
+
+        env.storage().persistent().set(
+            &ClaimKey::Spending(founder.clone(), count),
+            &SpendingRecord {
+                application_id: startup_data.application_id,
+                recipient: recipient.clone(),
+                amount,
+                purpose_cid,
+                claimed_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().persistent().set(
+            &ClaimKey::SpendingCount(founder.clone()),
+            &count.checked_add(1).expect("Spending log overflow"),
+        );
+    }
+
+    /// Payouts of unlocked funds across all of the founder's rounds,
+    /// `limit` entries from `start`, oldest first
+    pub fn get_spending_log(env: Env, founder: Address, start: u32, limit: u32) -> Vec<SpendingRecord> {
+        let count = Self::get_spending_count(env.clone(), founder.clone());
+
+        let mut page = Vec::new(&env);
+        let end = start.saturating_add(limit).min(count);
+        for i in start..end {
+            page.push_back(
+                env.storage()
+                    .persistent()
+                    .get(&ClaimKey::Spending(founder.clone(), i))
+                    .expect("Spending record not found"),
+            );
+        }
+
+        page
+    }
+
+    pub fn get_spending_count(env: Env, founder: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ClaimKey::SpendingCount(founder))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // INVESTOR TERMINATION
+    // ========================================================================
+
+    /// Investor in the current round proposes winding the startup down
+    pub fn propose_termination(env: Env, investor: Address, founder: Address) {
+        investor.require_auth();
+        Self::require_not_paused(&env);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        let proposal_key = TerminationKey::Proposal(startup_data.application_id);
+        if env.storage().instance().has(&proposal_key) {
+            panic!("Termination already proposed");
+        }
+
+        let weight = Self::termination_weight(&env, &startup_data, &investor);
+        let rules = Self::get_termination_rules(env.clone());
+        let now = env.ledger().timestamp();
+
+        env.storage().instance().set(
+            &proposal_key,
+            &TerminationProposal {
+                proposer: investor.clone(),
+                created_at: now,
+                voting_end: now.saturating_add(rules.voting_period),
+                votes_for: weight,
+                votes_against: 0,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&TerminationKey::TerminationVote(startup_data.application_id, investor), &now);
+    }
+
+    /// Investor votes on an open termination, weighted by their round investment
+    pub fn vote_termination(env: Env, investor: Address, founder: Address, support: bool) {
+        investor.require_auth();
+        Self::require_not_paused(&env);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))
+            .expect("Startup not found");
+
+        let proposal_key = TerminationKey::Proposal(startup_data.application_id);
+        let mut proposal: TerminationProposal = env
+            .storage()
+            .instance()
+            .get(&proposal_key)
+            .expect("No termination proposal");
+
+        if env.ledger().timestamp() > proposal.voting_end {
+            panic!("Termination voting ended");
+        }
+
+        // Votes are tagged with the proposal they were cast on, so votes
+        // from an earlier failed proposal don't count as voting again
+        let vote_key = TerminationKey::TerminationVote(startup_data.application_id, investor.clone());
+        if env.storage().instance().get::<_, u64>(&vote_key) == Some(proposal.created_at) {
+            panic!("Already voted");
+        }
+
+        let weight = Self::termination_weight(&env, &startup_data, &investor);
+        if support {
+            proposal.votes_for = Self::checked_add(proposal.votes_for, weight);
+        } else {
+            proposal.votes_against = Self::checked_add(proposal.votes_against, weight);
+        }
+
+        env.storage().instance().set(&vote_key, &proposal.created_at);
+        env.storage().instance().set(&proposal_key, &proposal);
+    }
+
+    /// Close termination voting. A passed vote freezes the escrow for
+    /// refunds and ends the founder's claims; a failed one can be re-proposed.
+    pub fn execute_termination(env: Env, founder: Address) -> bool {
+        Self::require_not_paused(&env);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        let proposal_key = TerminationKey::Proposal(startup_data.application_id);
+        let proposal: TerminationProposal = env
+            .storage()
+            .instance()
+            .get(&proposal_key)
+            .expect("No termination proposal");
+
+        if env.ledger().timestamp() <= proposal.voting_end {
+            panic!("Termination voting still open");
+        }
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        let rules = Self::get_termination_rules(env.clone());
+        let quorum = Self::checked_mul(startup_data.total_allocated, rules.quorum_bps as i128) / 10_000;
+        let passed = proposal.votes_for >= quorum && proposal.votes_for > proposal.votes_against;
+
+        if passed {
+            Self::wind_down(&env, &founder, startup_data, ApplicationOutcome::Terminated);
+        } else {
+            env.storage().instance().remove(&proposal_key);
+        }
+
+        passed
+    }
+
+    /// Investor reclaims their pro rata share of a terminated startup's escrow
+    pub fn claim_termination_refund(
+        env: Env,
+        investor: Address,
+        founder: Address,
+    ) -> i128 {
+        investor.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Claiming);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if !Self::is_wound_down(&env, &startup_data) && Self::closed_rounds(&env, &founder).is_empty() {
+            panic!("Startup not terminated or failed");
+        }
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        Self::refund_escrow_share(&env, &investor, &founder, &xlm_token, false)
+    }
+
+    fn termination_weight(env: &Env, startup_data: &StartupData, investor: &Address) -> i128 {
+        let weight: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ApplicationInvestment(startup_data.application_id, investor.clone()))
+            .unwrap_or(0);
+
+        if weight <= 0 {
+            panic!("Not an investor in this round");
+        }
+
+        weight
+    }
+
+    /// A transfer during an investor vote would let the same stake vote twice
+    fn require_no_investor_vote(env: &Env, founder: &Address) {
+        if let Some(startup_data) = env
+            .storage()
+            .instance()
+            .get::<_, StartupData>(&DataKey::Startup(founder.clone()))
+        {
+            if Self::current_outcome(env, &startup_data) != ApplicationOutcome::Approved {
+                return;
+            }
+
+            if env
+                .storage()
+                .instance()
+                .has(&TerminationKey::Proposal(startup_data.application_id))
+            {
+                panic!("Termination vote in progress");
+            }
+
+            if Self::open_extension_request(env, startup_data.application_id).is_some() {
+                panic!("Extension vote in progress");
+            }
+        }
+    }
+
+    /// The round's extension request, if it has not expired
+    fn open_extension_request(env: &Env, application_id: u64) -> Option<ExtensionRequest> {
+        env.storage()
+            .instance()
+            .get::<_, ExtensionRequest>(&DeadlineKey::Request(application_id))
+            .filter(|request| env.ledger().timestamp() <= request.expires_at)
+    }
+
+    // ========================================================================
+    // DISPUTES & ARBITRATION
+    // ========================================================================
+
+    /// Join the arbiter panel by staking, like a VC
+    pub fn register_arbiter(env: Env, arbiter: Address) {
+        arbiter.require_auth();
+        Self::require_not_paused_for(&env, PauseFlag::Staking);
+        Self::acquire_lock(&env, &arbiter);
+
+        if env.storage().instance().has(&DisputeKey::Arbiter(arbiter.clone())) {
+            Self::release_lock(&env, &arbiter);
+            panic!("Already an arbiter");
+        }
+
+        let stake = Self::get_arbiter_stake_required(env.clone());
+        let xlm_token = Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&arbiter, &env.current_contract_address(), &stake);
+
+        env.storage().instance().set(
+            &DisputeKey::Arbiter(arbiter.clone()),
+            &ArbiterData {
+                stake_amount: stake,
+                open_votes: 0,
+            },
+        );
+
+        let mut arbiters = Self::get_arbiters(env.clone());
+        arbiters.push_back(arbiter.clone());
+        env.storage().instance().set(&DisputeKey::Arbiters, &arbiters);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &arbiter);
+    }
+
+    /// Leave the panel and recover the remaining stake. Not allowed while
+    /// the arbiter has votes on unresolved disputes, unless the contract
+    /// was shut down and those disputes can no longer be resolved.
+    pub fn withdraw_arbiter(env: Env, arbiter: Address) -> i128 {
+        arbiter.require_auth();
+        let shutdown = Self::is_shutdown(env.clone());
+        if !shutdown {
+            Self::require_not_paused_for(&env, PauseFlag::Staking);
+        }
+        Self::acquire_lock(&env, &arbiter);
+
+        let arbiter_data: ArbiterData = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Arbiter(arbiter.clone()))
+            .expect("Not an arbiter");
+
+        if arbiter_data.open_votes > 0 && !shutdown {
+            Self::release_lock(&env, &arbiter);
+            panic!("Arbiter has open disputes");
+        }
+
+        env.storage()
+            .instance()
+            .remove(&DisputeKey::Arbiter(arbiter.clone()));
+
+        let mut arbiters = Self::get_arbiters(env.clone());
+        if let Some(index) = arbiters.first_index_of(&arbiter) {
+            arbiters.remove(index);
+        }
+        env.storage().instance().set(&DisputeKey::Arbiters, &arbiters);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        if arbiter_data.stake_amount > 0 {
+            let token_client = token::Client::new(&env, &xlm_token);
+            token_client.transfer(&env.current_contract_address(), &arbiter, &arbiter_data.stake_amount);
+        }
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &arbiter);
+        arbiter_data.stake_amount
+    }
+
+    /// Founder or a current-round investor holding at least the minimum share
+    /// of the round opens a dispute over the escrow. Milestone unlocks and
+    /// founder claims are frozen until it is resolved. An opener whose
+    /// dispute is dismissed must wait a voting period before opening another.
+    pub fn open_dispute(
+        env: Env,
+        caller: Address,
+        founder: Address,
+        claim_type: ClaimType,
+        evidence_cid: String,
+    ) -> u64 {
+        caller.require_auth();
+        Self::require_not_paused(&env);
+        Self::validate_cid(&evidence_cid);
+
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::current_outcome(&env, &startup_data) != ApplicationOutcome::Approved {
+            panic!("Startup is not active");
+        }
+
+        let invested: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ApplicationInvestment(startup_data.application_id, caller.clone()))
+            .unwrap_or(0);
+        if caller != founder && invested <= 0 {
+            panic!("Not a party to this startup");
+        }
+        if caller != founder
+            && Self::checked_mul(invested, 10_000)
+                < Self::checked_mul(startup_data.total_allocated, Self::get_dispute_min_share(env.clone()) as i128)
+        {
+            panic!("Share too small to open a dispute");
+        }
+
+        let cooldown: u64 = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::OpenerCooldown(founder.clone(), caller.clone()))
+            .unwrap_or(0);
+        if env.ledger().timestamp() < cooldown {
+            panic!("Dispute cooldown active");
+        }
+
+        if env.storage().instance().has(&DisputeKey::Open(founder.clone())) {
+            panic!("Dispute open");
+        }
+
+        let amount = match claim_type {
+            ClaimType::MilestoneUnlock => {
+                if startup_data.current_milestone >= startup_data.milestone_count {
+                    panic!("All milestones already unlocked");
+                }
+                Self::milestone_tranche(&env, &startup_data)
+            }
+            ClaimType::Refund => Self::available_escrow(&env, &startup_data),
+        };
+        if amount <= 0 {
+            panic!("No escrow in dispute");
+        }
+
+        let dispute_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::NextDisputeId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::NextDisputeId, &dispute_id.checked_add(1).expect("Dispute id overflow"));
+
+        let now = env.ledger().timestamp();
+        let dispute = Dispute {
+            dispute_id,
+            founder: founder.clone(),
+            application_id: startup_data.application_id,
+            opened_by: caller,
+            claim_type,
+            evidence_cid,
+            amount,
+            opened_at: now,
+            voting_end: now.saturating_add(Self::get_dispute_voting_period(env.clone())),
+            release_votes: 0,
+            refund_votes: 0,
+            split_votes: 0,
+            status: DisputeStatus::Open,
+        };
+
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Dispute(dispute_id), &dispute);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Open(founder), &dispute_id);
+
+        dispute_id
+    }
+
+    /// Panel arbiter votes on an open dispute before its deadline
+    pub fn vote_dispute(env: Env, arbiter: Address, dispute_id: u64, ruling: Ruling) {
+        arbiter.require_auth();
+        Self::require_not_paused(&env);
+
+        let mut arbiter_data: ArbiterData = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Arbiter(arbiter.clone()))
+            .expect("Not an arbiter");
+
+        if arbiter_data.stake_amount < Self::get_arbiter_stake_required(env.clone()) {
+            panic!("Insufficient arbiter stake");
+        }
+
+        let mut dispute: Dispute = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Dispute(dispute_id))
+            .expect("Dispute not found");
+
+        if dispute.status != DisputeStatus::Open || env.ledger().timestamp() > dispute.voting_end {
+            panic!("Dispute voting closed");
+        }
+
+        // Parties can't judge their own dispute
+        if arbiter == dispute.founder
+            || env
+                .storage()
+                .instance()
+                .has(&DataKey::ApplicationInvestment(dispute.application_id, arbiter.clone()))
+        {
+            panic!("Arbiter is a party to this dispute");
+        }
+
+        let vote_key = DisputeKey::ArbiterVote(dispute_id, arbiter.clone());
+        if env.storage().instance().has(&vote_key) {
+            panic!("Already voted");
+        }
+
+        match ruling {
+            Ruling::Release => dispute.release_votes = dispute.release_votes.saturating_add(1),
+            Ruling::Refund => dispute.refund_votes = dispute.refund_votes.saturating_add(1),
+            Ruling::Split => dispute.split_votes = dispute.split_votes.saturating_add(1),
+        }
+
+        arbiter_data.open_votes = arbiter_data.open_votes.saturating_add(1);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Arbiter(arbiter.clone()), &arbiter_data);
+
+        let mut voters: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Voters(dispute_id))
+            .unwrap_or(Vec::new(&env));
+        voters.push_back(arbiter);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Voters(dispute_id), &voters);
+
+        env.storage().instance().set(&vote_key, &ruling);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Dispute(dispute_id), &dispute);
+    }
+
+    /// Apply the panel's ruling once voting has ended. The most-voted ruling
+    /// wins and ties split; with no votes the escrow stays where it is.
+    pub fn resolve_dispute(env: Env, dispute_id: u64) -> DisputeStatus {
+        Self::require_not_paused(&env);
+
+        let mut dispute: Dispute = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Dispute(dispute_id))
+            .expect("Dispute not found");
+
+        if dispute.status != DisputeStatus::Open {
+            panic!("Dispute already resolved");
+        }
+        if env.ledger().timestamp() <= dispute.voting_end {
+            panic!("Dispute voting still open");
+        }
+
+        let status = if dispute.release_votes == 0 && dispute.refund_votes == 0 && dispute.split_votes == 0 {
+            DisputeStatus::Dismissed
+        } else if dispute.release_votes > dispute.refund_votes && dispute.release_votes > dispute.split_votes {
+            Self::apply_ruling(&env, &dispute, Ruling::Release);
+            DisputeStatus::Released
+        } else if dispute.refund_votes > dispute.release_votes && dispute.refund_votes > dispute.split_votes {
+            Self::apply_ruling(&env, &dispute, Ruling::Refund);
+            DisputeStatus::Refunded
+        } else {
+            Self::apply_ruling(&env, &dispute, Ruling::Split);
+            DisputeStatus::Split
+        };
+
+        for arbiter in env
+            .storage()
+            .instance()
+            .get::<_, Vec<Address>>(&DisputeKey::Voters(dispute_id))
+            .unwrap_or(Vec::new(&env))
+            .iter()
+        {
+            if let Some(mut arbiter_data) = env
+                .storage()
+                .instance()
+                .get::<_, ArbiterData>(&DisputeKey::Arbiter(arbiter.clone()))
+            {
+                arbiter_data.open_votes = arbiter_data.open_votes.saturating_sub(1);
+                env.storage()
+                    .instance()
+                    .set(&DisputeKey::Arbiter(arbiter), &arbiter_data);
+            }
+        }
+
+        if status == DisputeStatus::Dismissed {
+            let cooldown = env
+                .ledger()
+                .timestamp()
+                .saturating_add(Self::get_dispute_voting_period(env.clone()));
+            env.storage().instance().set(
+                &DisputeKey::OpenerCooldown(dispute.founder.clone(), dispute.opened_by.clone()),
+                &cooldown,
+            );
+        }
+
+        dispute.status = status;
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Dispute(dispute_id), &dispute);
+        env.storage()
+            .instance()
+            .remove(&DisputeKey::Open(dispute.founder));
+
+        status
+    }
+
+    /// Investor withdraws their pro rata share of the round's refund pool:
+    /// escrow ruled back in disputes or returned for missed milestones.
+    /// Also works after an emergency shutdown.
+    pub fn claim_dispute_refund(
+        env: Env,
+        investor: Address,
+        founder: Address,
+    ) -> i128 {
+        investor.require_auth();
+        if !Self::is_shutdown(env.clone()) {
+            Self::require_not_paused_for(&env, PauseFlag::Claiming);
+        }
+        Self::acquire_lock(&env, &investor);
+
+        let round = Self::refund_round(&env, &founder, |round, _| {
+            Self::dispute_refund_owed(&env, round, &investor) > 0
+        });
+        let Some(mut startup_data) = round else {
+            Self::release_lock(&env, &investor);
+            panic!("Nothing to withdraw");
+        };
+
+        let refund = Self::dispute_refund_owed(&env, &startup_data, &investor);
+        let paid_key = DisputeKey::RefundPaid(startup_data.application_id, investor.clone());
+        let paid: i128 = env.storage().instance().get(&paid_key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&paid_key, &Self::checked_add(paid, refund));
+
+        startup_data.refunded_balance = Self::checked_add(startup_data.refunded_balance, refund);
+        Self::save_round(&env, &founder, &startup_data);
+
+        Self::release_position(&env, &investor, &founder, refund);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&env.current_contract_address(), &investor, &refund);
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &investor);
+        refund
+    }
+
+    /// Admin slashes a misbehaving arbiter's stake into the treasury
+    pub fn slash_arbiter_stake(env: Env, admin: Address, arbiter: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let mut arbiter_data: ArbiterData = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::Arbiter(arbiter.clone()))
+            .expect("Not an arbiter");
+
+        if amount <= 0 || amount > arbiter_data.stake_amount {
+            panic!("Invalid slash amount");
+        }
+
+        arbiter_data.stake_amount = Self::checked_sub(arbiter_data.stake_amount, amount);
+        env.storage()
+            .instance()
+            .set(&DisputeKey::Arbiter(arbiter), &arbiter_data);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        Self::credit_treasury(&env, &xlm_token, amount);
+        Self::check_invariants(&env, &xlm_token);
+    }
+
+    fn apply_ruling(env: &Env, dispute: &Dispute, ruling: Ruling) {
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(dispute.founder.clone()))
+            .expect("Startup not found");
+
+        // The round may have moved on, e.g. through termination
+        if startup_data.application_id != dispute.application_id
+            || Self::current_outcome(env, &startup_data) != ApplicationOutcome::Approved
+        {
+            return;
+        }
+
+        let amount = dispute.amount.min(Self::available_escrow(env, &startup_data));
+        let released = match ruling {
+            Ruling::Release => amount,
+            Ruling::Refund => 0,
+            Ruling::Split => amount / 2,
+        };
+        let refunded = Self::checked_sub(amount, released);
+
+        startup_data.unlocked_balance = Self::checked_add(startup_data.unlocked_balance, released);
+
+        if refunded > 0 {
+            let pool = Self::dispute_refund_pool(env, startup_data.application_id);
+            env.storage().instance().set(
+                &DisputeKey::RefundPool(startup_data.application_id),
+                &Self::checked_add(pool, refunded),
+            );
+        }
+
+        // A milestone dispute settles that milestone whichever way it went
+        if dispute.claim_type == ClaimType::MilestoneUnlock
+            && startup_data.current_milestone < startup_data.milestone_count
+        {
+            startup_data.current_milestone = startup_data.current_milestone.checked_add(1).expect("Milestone overflow");
+            startup_data.last_milestone_time = env.ledger().timestamp();
+
+            if startup_data.current_milestone == startup_data.milestone_count {
+                Self::set_outcome(env, &startup_data, ApplicationOutcome::Completed);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(dispute.founder.clone()), &startup_data);
+    }
+
+    /// Escrow that can still be unlocked or ruled on
+    fn available_escrow(env: &Env, startup_data: &StartupData) -> i128 {
+        Self::checked_sub(
+            Self::checked_sub(startup_data.total_allocated, startup_data.unlocked_balance),
+            Self::dispute_refund_pool(env, startup_data.application_id)
+        )
+    }
+
+    /// 25% of the round, except the final milestone which releases
+    /// everything left so no rounding dust stays in escrow
+    fn milestone_tranche(env: &Env, startup_data: &StartupData) -> i128 {
+        let available = Self::available_escrow(env, startup_data);
+        if startup_data.current_milestone + 1 >= startup_data.milestone_count {
+            return available;
+        }
+
+        let tranche = Self::checked_mul(startup_data.total_allocated, 25) / 100;
+        tranche.min(available)
+    }
+
+    /// An investor's share of a round's dispute refund pool not yet paid
+    fn dispute_refund_owed(env: &Env, round: &StartupData, investor: &Address) -> i128 {
+        let investment = Self::round_investment(env, round, investor);
+        if investment <= 0 {
+            return 0;
+        }
+
+        let entitled = Self::checked_mul(
+            Self::dispute_refund_pool(env, round.application_id),
+            investment
+        ) / round.total_allocated;
+        let paid: i128 = env
+            .storage()
+            .instance()
+            .get(&DisputeKey::RefundPaid(round.application_id, investor.clone()))
+            .unwrap_or(0);
+
+        Self::checked_sub(entitled, paid)
+    }
+
+    fn dispute_refund_pool(env: &Env, application_id: u64) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DisputeKey::RefundPool(application_id))
+            .unwrap_or(0)
+    }
+
+    fn require_no_open_dispute(env: &Env, founder: &Address) {
+        if env.storage().instance().has(&DisputeKey::Open(founder.clone())) {
+            panic!("Dispute open");
+        }
+    }
+
+    // ========================================================================
+    // FOUNDER TEAM (MULTI-ADDRESS OWNERSHIP)
+    // ========================================================================
+
+    /// Founder hands ownership of the startup to a team of addresses.
+    /// The founder address remains the startup's identifier but its key
+    /// loses sole control of claims. The key that acts for the startup
+    /// can then only be rotated with the team's threshold.
+    pub fn set_team(
+        env: Env,
+        founder: Address,
+        members: Vec<Address>,
+        payout_bps: Vec<u32>,
+        threshold: u32,
+    ) {
+        Self::require_founder(&env, &founder);
+        Self::require_not_paused(&env);
+
+        if !env.storage().instance().has(&DataKey::Startup(founder.clone())) {
+            panic!("Startup not found");
+        }
+
+        Self::require_no_team(&env, &founder);
+
+        let team = Team { members, payout_bps, threshold };
+        Self::validate_team(&team);
+        env.storage().instance().set(&DataKey::Team(founder), &team);
+    }
+
+    /// Replace the team's members, payout split or threshold
+    pub fn update_team(
+        env: Env,
+        founder: Address,
+        signers: Vec<Address>,
+        members: Vec<Address>,
+        payout_bps: Vec<u32>,
+        threshold: u32,
+    ) {
+        Self::require_not_paused(&env);
+
+        let current = Self::get_team(env.clone(), founder.clone()).expect("Team not set");
+        Self::require_team_approval(&current, &signers);
+
+        let team = Team { members, payout_bps, threshold };
+        Self::validate_team(&team);
+        env.storage().instance().set(&DataKey::Team(founder), &team);
+    }
+
+    /// Swap a (possibly lost) member key for a new one, keeping its payout share
+    pub fn rotate_team_member(
+        env: Env,
+        founder: Address,
+        signers: Vec<Address>,
+        old_member: Address,
+        new_member: Address,
+    ) {
+        Self::require_not_paused(&env);
+
+        let mut team = Self::get_team(env.clone(), founder.clone()).expect("Team not set");
+        Self::require_team_approval(&team, &signers);
+
+        let index = team.members.first_index_of(&old_member).expect("Not a team member");
+        team.members.set(index, new_member);
+
+        Self::validate_team(&team);
+        env.storage().instance().set(&DataKey::Team(founder), &team);
+    }
+
+    /// Team claims unlocked funds, split across members by basis points.
+    /// Also works after an emergency shutdown.
+    pub fn claim_funds_team(
+        env: Env,
+        founder: Address,
+        signers: Vec<Address>,
+    ) -> i128 {
+        if !Self::is_shutdown(env.clone()) {
+            Self::require_not_paused_for(&env, PauseFlag::Claiming);
+        }
+
+        let team = Self::get_team(env.clone(), founder.clone()).expect("Team not set");
+        Self::require_team_approval(&team, &signers);
+        Self::acquire_lock(&env, &founder);
+
+        let mut startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+
+        if Self::is_wound_down(&env, &startup_data) {
+            Self::release_lock(&env, &founder);
+            panic!("Startup terminated or failed");
+        }
+
+        if env.storage().instance().has(&DisputeKey::Open(founder.clone())) {
+            Self::release_lock(&env, &founder);
+            panic!("Dispute open");
+        }
+
+        let claimable = Self::checked_sub(
+            startup_data.unlocked_balance,
+            startup_data.claimed_balance
+        );
+
+        if claimable <= 0 {
+            Self::release_lock(&env, &founder);
+            panic!("No funds to claim");
+        }
+
+        startup_data.claimed_balance = Self::checked_add(
+            startup_data.claimed_balance,
+            claimable
+        );
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Startup(founder.clone()), &startup_data);
+
+        // Rounding dust goes to the last member
+        let xlm_token = Self::get_config(env.clone()).token;
+        let token_client = token::Client::new(&env, &xlm_token);
+        let mut paid = 0i128;
+        let last = team.members.len() - 1;
+        for (i, member) in team.members.iter().enumerate() {
+            let share = if i as u32 == last {
+                Self::checked_sub(claimable, paid)
+            } else {
+                Self::checked_mul(claimable, team.payout_bps.get_unchecked(i as u32) as i128) / 10_000
+            };
+
+            if share > 0 {
+                Self::log_spending(&env, &founder, &startup_data, &member, share, None);
+                token_client.transfer(&env.current_contract_address(), &member, &share);
+            }
+            paid = Self::checked_add(paid, share);
+        }
+
+        Self::check_invariants(&env, &xlm_token);
+        Self::release_lock(&env, &founder);
+        claimable
+    }
+
+    fn validate_team(team: &Team) {
+        let count = team.members.len();
+        if count == 0 || team.payout_bps.len() != count {
+            panic!("Invalid team");
+        }
+
+        if team.threshold == 0 || team.threshold > count {
+            panic!("Invalid threshold");
+        }
+
+        for (i, member) in team.members.iter().enumerate() {
+            if team.members.first_index_of(&member) != Some(i as u32) {
+                panic!("Duplicate team member");
+            }
+        }
+
+        let total_bps: u32 = team.payout_bps.iter().sum();
+        if total_bps != 10_000 {
+            panic!("Payout split must total 10000 bps");
+        }
+    }
+
+    /// Every signer must be a distinct member and authorize the call
+    fn require_team_approval(team: &Team, signers: &Vec<Address>) {
+        for (i, signer) in signers.iter().enumerate() {
+            if !team.members.contains(&signer) {
+                panic!("Not a team member");
+            }
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                panic!("Duplicate signer");
+            }
+            signer.require_auth();
+        }
+
+        if signers.len() < team.threshold {
+            panic!("Not enough team signatures");
+        }
+    }
+
+    /// Hand control of the startup to `new_key`. A team-owned startup rotates
+    /// with the team's threshold, otherwise the current key signs. The
+    /// founder address stays the startup's identifier.
+    pub fn rotate_founder(env: Env, founder: Address, signers: Vec<Address>, new_key: Address) {
+        Self::require_not_paused(&env);
+
+        if !env.storage().instance().has(&DataKey::Startup(founder.clone())) {
+            panic!("Startup not found");
+        }
+
+        match Self::get_team(env.clone(), founder.clone()) {
+            Some(team) => Self::require_team_approval(&team, &signers),
+            None => {
+                Self::require_founder(&env, &founder);
+            }
+        }
+
+        env.storage().instance().set(&DataKey::FounderKey(founder), &new_key);
+    }
+
+    /// Admin recovers a team-less startup whose founder key was lost
+    pub fn recover_founder_key(env: Env, admin: Address, founder: Address, new_key: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if !env.storage().instance().has(&DataKey::Startup(founder.clone())) {
+            panic!("Startup not found");
+        }
+        Self::require_no_team(&env, &founder);
+
+        env.storage().instance().set(&DataKey::FounderKey(founder), &new_key);
+    }
+
+    /// The key that acts for a startup: the founder address until rotated
+    fn founder_key(env: &Env, founder: &Address) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::FounderKey(founder.clone()))
+            .unwrap_or(founder.clone())
+    }
+
+    fn require_founder(env: &Env, founder: &Address) -> Address {
+        let key = Self::founder_key(env, founder);
+        key.require_auth();
+        key
+    }
+
+    fn require_no_team(env: &Env, founder: &Address) {
+        if env.storage().instance().has(&DataKey::Team(founder.clone())) {
+            panic!("Startup is team-owned");
+        }
+    }
+
+    // ========================================================================
+    // TREASURY (FEES, SLASHING, PLATFORM TAKE-RATE)
+    // ========================================================================
+
+    /// Move part of a VC's stake into the treasury (admin only)
+    pub fn slash_vc_stake(env: Env, admin: Address, vc_address: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let mut vc_data: VCData = env
+            .storage()
+            .instance()
+            .get(&DataKey::VCData(vc_address.clone()))
+            .expect("Not a VC");
+
+        if amount <= 0 || amount > vc_data.stake_amount {
+            panic!("Invalid slash amount");
+        }
+
+        vc_data.stake_amount = Self::checked_sub(vc_data.stake_amount, amount);
+        env.storage()
+            .instance()
+            .set(&DataKey::VCData(vc_address), &vc_data);
+
+        let xlm_token = Self::get_config(env.clone()).token;
+        Self::credit_treasury(&env, &xlm_token, amount);
+        Self::check_invariants(&env, &xlm_token);
+    }
+
+    /// Withdraw treasury funds, subject to the per-period spend limit (admin only)
+    pub fn treasury_withdraw(env: Env, admin: Address, xlm_token: Address, to: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_not_paused(&env);
+        Self::require_admin(&env, &admin);
+
+        let balance = Self::get_treasury_balance(env.clone(), xlm_token.clone());
+        if amount <= 0 || amount > balance {
+            panic!("Insufficient treasury balance");
+        }
+
+        if let Some(limit) = env
+            .storage()
+            .instance()
+            .get::<_, SpendLimit>(&DataKey::TreasurySpendLimit)
+        {
+            let now = env.ledger().timestamp();
+            let spent_key = DataKey::TreasurySpent(xlm_token.clone());
+            let mut window: SpendWindow = env
+                .storage()
+                .instance()
+                .get(&spent_key)
+                .unwrap_or(SpendWindow { window_start: now, spent: 0 });
+
+            if now >= window.window_start.saturating_add(limit.period) {
+                window = SpendWindow { window_start: now, spent: 0 };
+            }
+
+            window.spent = Self::checked_add(window.spent, amount);
+            if window.spent > limit.max_per_period {
+                panic!("Treasury spend limit exceeded");
+            }
+
+            env.storage().instance().set(&spent_key, &window);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Treasury(xlm_token.clone()), &Self::checked_sub(balance, amount));
+
+        let token_client = token::Client::new(&env, &xlm_token);
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        Self::check_invariants(&env, &xlm_token);
+    }
+
+    /// Compare the contract's obligations with its actual token balance.
+    /// Assumes stakes and investments were all made in `xlm_token`.
+    pub fn reconcile(env: Env, xlm_token: Address) -> Reconciliation {
+        let totals = Self::audit_totals(env.clone());
+        let escrowed = Self::checked_add(totals.total_escrow, totals.total_unlocked_unclaimed);
+        let treasury = Self::get_treasury_balance(env.clone(), xlm_token.clone());
+        let voter_rewards = Self::reward_liabilities(&env, &xlm_token);
+        let revenue = Self::revenue_liabilities(&env, &xlm_token);
+        let token_balance = token::Client::new(&env, &xlm_token).balance(&env.current_contract_address());
+        let owed = [totals.total_stakes, escrowed, treasury, voter_rewards, revenue]
+            .iter()
+            .fold(0, |sum, amount| Self::checked_add(sum, *amount));
+
+        Reconciliation {
+            stakes: totals.total_stakes,
+            escrowed,
+            treasury,
+            voter_rewards,
+            revenue,
+            token_balance,
+            balanced: owed == token_balance,
+        }
+    }
+
+    fn credit_treasury(env: &Env, token: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+
+        let key = DataKey::Treasury(token.clone());
+        let balance: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&key, &Self::checked_add(balance, amount));
+    }
+
+    // ========================================================================
+    // SOLVENCY AUDIT
+    // ========================================================================
+
+    /// Aggregate stakes, escrow, unlocked-but-unclaimed, claimed and refunded
+    /// amounts across all VCs and startups
+    pub fn audit_totals(env: Env) -> AuditTotals {
+        let mut totals = AuditTotals {
+            total_stakes: 0,
+            total_escrow: 0,
+            total_unlocked_unclaimed: 0,
+            total_claimed: 0,
+            total_refunded: 0,
+            total_revenue_unclaimed: 0,
+        };
+
+        for vc in Self::get_all_vcs(env.clone()).iter() {
+            if let Some(vc_data) = Self::get_vc_data(env.clone(), vc) {
+                totals.total_stakes = Self::checked_add(totals.total_stakes, vc_data.stake_amount);
+            }
+        }
+
+        for arbiter in Self::get_arbiters(env.clone()).iter() {
+            if let Some(arbiter_data) = Self::get_arbiter(env.clone(), arbiter) {
+                totals.total_stakes = Self::checked_add(totals.total_stakes, arbiter_data.stake_amount);
+            }
+        }
+
+        for founder in Self::get_all_startups(env.clone()).iter() {
+            if let Some(startup_data) = Self::get_startup_status(env.clone(), founder.clone()) {
+                let escrow = Self::checked_sub(
+                    Self::checked_sub(startup_data.total_allocated, startup_data.unlocked_balance),
+                    startup_data.refunded_balance
+                );
+                let unclaimed = Self::checked_sub(
+                    startup_data.unlocked_balance,
+                    startup_data.claimed_balance
+                );
+
+                totals.total_escrow = Self::checked_add(totals.total_escrow, escrow);
+                totals.total_unlocked_unclaimed = Self::checked_add(totals.total_unlocked_unclaimed, unclaimed);
+                totals.total_claimed = Self::checked_add(totals.total_claimed, startup_data.claimed_balance);
+                totals.total_refunded = Self::checked_add(totals.total_refunded, startup_data.refunded_balance);
+            }
+
+            // Closed rounds still owe their investors' refunds
+            for round in Self::closed_rounds(&env, &founder).iter() {
+                let escrow = Self::checked_sub(
+                    Self::checked_sub(round.total_allocated, round.unlocked_balance),
+                    round.refunded_balance
+                );
+                totals.total_escrow = Self::checked_add(totals.total_escrow, escrow);
+                totals.total_claimed = Self::checked_add(totals.total_claimed, round.claimed_balance);
+                totals.total_refunded = Self::checked_add(totals.total_refunded, round.refunded_balance);
+            }
+
+            let pool = Self::load_revenue_pool(&env, &founder);
+            totals.total_revenue_unclaimed = Self::checked_add(
+                totals.total_revenue_unclaimed,
+                Self::checked_sub(pool.total_deposited, pool.total_claimed)
+            );
+        }
+
+        totals
+    }
+
+    /// Deposited revenue not yet paid to investors, in pools paid in `xlm_token`
+    fn revenue_liabilities(env: &Env, xlm_token: &Address) -> i128 {
+        Self::get_all_startups(env.clone())
+            .iter()
+            .map(|founder| Self::load_revenue_pool(env, &founder))
+            .filter(|pool| pool.token.as_ref() == Some(xlm_token))
+            .fold(0, |sum, pool| {
+                Self::checked_add(sum, Self::checked_sub(pool.total_deposited, pool.total_claimed))
+            })
+    }
+
+    /// Whether the contract holds at least what it owes in `xlm_token`
+    pub fn is_solvent(env: Env, xlm_token: Address) -> bool {
+        let totals = Self::audit_totals(env.clone());
+        let obligations = [
+            totals.total_stakes,
+            totals.total_escrow,
+            totals.total_unlocked_unclaimed,
+            Self::revenue_liabilities(&env, &xlm_token),
+            Self::get_treasury_balance(env.clone(), xlm_token.clone()),
+            Self::reward_liabilities(&env, &xlm_token),
+        ]
+        .iter()
+        .fold(0, |sum, amount| Self::checked_add(sum, *amount));
+
+        token::Client::new(&env, &xlm_token).balance(&env.current_contract_address()) >= obligations
+    }
+
+    /// Enable or disable the solvency check after every mutation (admin only)
+    pub fn set_invariant_checks(env: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::InvariantChecks, &enabled);
+    }
+
+    /// Run the solvency check if invariant checks are enabled
+    fn check_invariants(env: &Env, xlm_token: &Address) {
+        let enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::InvariantChecks)
+            .unwrap_or(false);
+
+        if enabled && !Self::is_solvent(env.clone(), xlm_token.clone()) {
+            panic!("Solvency invariant violated");
+        }
+    }
+
+    // ========================================================================
+    // CONFIGURATION UPDATES
+    // ========================================================================
+    
+    /// Update VC stake requirement (admin only)
+    pub fn update_vc_stake_required(env: Env, admin: Address, new_amount: i128) {
+        admin.require_auth();
+        
+        let mut config: ContractConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Contract not initialized");
+        
+        if admin != config.admin {
+            panic!("Unauthorized: not admin");
+        }
+        Self::require_not_shutdown(&env);
+        
+        config.vc_stake_required = new_amount;
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+    
+    /// Update funding cap and ticket limits for vc_invest (admin only)
+    pub fn update_investment_limits(env: Env, admin: Address, limits: InvestmentLimits) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if limits.min_ticket < 0
+            || limits.max_ticket < 0
+            || (limits.max_ticket > 0 && limits.min_ticket > limits.max_ticket)
+        {
+            panic!("Invalid investment limits");
+        }
+
+        env.storage().instance().set(&DataKey::InvestmentLimits, &limits);
+    }
+
+    /// Update the allowed funding goal range for new applications (admin only)
+    pub fn update_funding_bounds(env: Env, admin: Address, bounds: FundingBounds) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if bounds.min_funding_goal < 0
+            || (bounds.max_funding_goal > 0 && bounds.min_funding_goal > bounds.max_funding_goal)
+        {
+            panic!("Invalid funding bounds");
+        }
+
+        env.storage().instance().set(&DataKey::FundingBounds, &bounds);
+    }
+
+    /// Update the wait between a closed application and the next one (admin only)
+    pub fn update_reapply_cooldown(env: Env, admin: Address, cooldown: u64) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::ReapplyCooldown, &cooldown);
+    }
+
+    /// Update the fee charged on every application (admin only)
+    pub fn update_application_fee(env: Env, admin: Address, new_fee: i128) {
+        admin.require_auth();
+        let mut config = Self::require_admin(&env, &admin);
+
+        if new_fee < 0 {
+            panic!("Fee cannot be negative");
+        }
+
+        config.application_fee = new_fee;
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    /// Set the token application fees are collected in (admin only)
+    pub fn set_fee_token(env: Env, admin: Address, fee_token: Address) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::FeeToken, &fee_token);
+    }
+
+    /// Update the platform fee taken from each investment (admin only)
+    pub fn update_platform_fee(env: Env, admin: Address, fee_bps: u32) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if fee_bps > 10_000 {
+            panic!("Fee cannot exceed 10000 bps");
+        }
+
+        env.storage().instance().set(&DataKey::PlatformFeeBps, &fee_bps);
+    }
+
+    /// Update the treasury withdrawal limit (admin only)
+    pub fn update_treasury_spend_limit(env: Env, admin: Address, limit: SpendLimit) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if limit.max_per_period < 0 || limit.period == 0 {
+            panic!("Invalid spend limit");
+        }
+
+        env.storage().instance().set(&DataKey::TreasurySpendLimit, &limit);
+    }
+
+    /// Update the share of each application fee set aside for voters (admin only)
+    pub fn update_reward_pool_bps(env: Env, admin: Address, bps: u32) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if bps > 10_000 {
+            panic!("Share cannot exceed 10000 bps");
+        }
+
+        env.storage().instance().set(&DataKey::RewardPoolBps, &bps);
+    }
+
+    /// Update how many VC reviews an application needs before approval (admin only)
+    pub fn update_min_reviews(env: Env, admin: Address, min_reviews: u32) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&DataKey::MinReviews, &min_reviews);
+    }
+
+    /// Update VC tier thresholds and ticket limits (admin only)
+    pub fn update_tier_config(env: Env, admin: Address, tiers: TierConfig) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if tiers.seed_fund_stake < 0 || tiers.lead_stake < tiers.seed_fund_stake {
+            panic!("Invalid tier thresholds");
+        }
+
+        env.storage().instance().set(&DataKey::TierConfig, &tiers);
+    }
+
+    /// Register or remove a KYC attester (admin only)
+    pub fn set_kyc_attester(env: Env, admin: Address, attester: Address, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if enabled {
+            env.storage().instance().set(&DataKey::KycAttester(attester), &true);
+        } else {
+            env.storage().instance().remove(&DataKey::KycAttester(attester));
+        }
+    }
+
+    /// Update the per-user community contribution cap (admin only)
+    pub fn update_community_user_cap(env: Env, admin: Address, cap: i128) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if cap < 0 {
+            panic!("Invalid community cap");
+        }
+
+        env.storage().instance().set(&DataKey::CommunityUserCap, &cap);
+    }
+
+    /// Require every round to publish a voter allowlist before anyone can
+    /// vote on it (admin only)
+    pub fn set_allowlist_required(env: Env, admin: Address, required: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage()
+            .instance()
+            .set(&EligibilityKey::AllowlistRequired, &required);
+    }
+
+    /// Publish the Merkle root of voters eligible for an application's vote.
+    /// The root can only change before anyone has voted.
+    pub fn set_voter_root(env: Env, admin: Address, application_id: u64, root: BytesN<32>) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        let record: ApplicationRecord = env
+            .storage()
+            .instance()
+            .get(&DataKey::Application(application_id))
+            .expect("Application not found");
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(record.founder))
+            .expect("Startup not found");
+
+        if record.outcome != ApplicationOutcome::Pending
+            || startup_data.application_id != application_id
+            || env.ledger().timestamp() > startup_data.voting_end_time
+        {
+            panic!("Voting period ended");
+        }
+
+        let tally: InvestorTally = env
+            .storage()
+            .instance()
+            .get(&ConflictKey::InvestorTally(application_id))
+            .unwrap_or(InvestorTally { yes_votes: 0, no_votes: 0 });
+        if startup_data.yes_votes != 0
+            || startup_data.no_votes != 0
+            || tally.yes_votes != 0
+            || tally.no_votes != 0
+        {
+            panic!("Voting already started");
+        }
+
+        env.storage()
+            .instance()
+            .set(&EligibilityKey::VoterRoot(application_id), &root);
+    }
+
+    pub fn update_conflict_rules(env: Env, admin: Address, rules: ConflictRules) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        env.storage().instance().set(&ConflictKey::ConflictRules, &rules);
+    }
+
+    /// Add or remove an address the admin knows to be tied to the founder
+    pub fn set_related_party(env: Env, admin: Address, founder: Address, party: Address, related: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        let key = ConflictKey::RelatedParty(founder, party);
+        if related {
+            env.storage().instance().set(&key, &true);
+        } else {
+            env.storage().instance().remove(&key);
+        }
+    }
+
+    pub fn update_deadline_rules(env: Env, admin: Address, rules: DeadlineRules) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if rules.return_bps > 10_000 {
+            panic!("Invalid return share");
+        }
+
+        env.storage().instance().set(&DeadlineKey::DeadlineRules, &rules);
+    }
+
+    pub fn set_oracle(env: Env, admin: Address, oracle: Address, enabled: bool) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if enabled {
+            env.storage().instance().set(&OracleKey::Oracle(oracle), &true);
+        } else {
+            env.storage().instance().remove(&OracleKey::Oracle(oracle));
+        }
+    }
+
+    pub fn update_arbiter_stake_required(env: Env, admin: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if amount <= 0 {
+            panic!("Invalid stake amount");
+        }
+
+        env.storage().instance().set(&DisputeKey::ArbiterStake, &amount);
+    }
+
+    pub fn update_dispute_voting_period(env: Env, admin: Address, period: u64) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
+
+        if period == 0 {
+            panic!("Invalid voting period");
+        }
+
+        env.storage().instance().set(&DisputeKey::VotingPeriod, &period);
+    }
+
+    /// Minimum share of the round, in bps, an investor needs to open a dispute
+    pub fn update_dispute_min_share(env: Env, admin: Address, min_share_bps: u32) {
+        admin.require_auth();
+        Self::require_admin(&env, &admin);
 
-        // Transfer after state update
-        let token_client = token::Client::new(&env, &xlm_token);
-        token_client.transfer(&env.current_contract_address(), &founder, &claimable);
+        if min_share_bps > 10_000 {
+            panic!("Invalid dispute share");
+        }
 
-        Self::release_lock(&env, &founder);
+        env.storage().instance().set(&DisputeKey::MinOpenerShare, &min_share_bps);
     }
 
-    // ========================================================================
-    // CONFIGURATION UPDATES
-    // ========================================================================
-    
-    /// Update VC stake requirement (admin only)
-    pub fn update_vc_stake_required(env: Env, admin: Address, new_amount: i128) {
+    pub fn update_termination_rules(env: Env, admin: Address, rules: TerminationRules) {
         admin.require_auth();
-        
-        let mut config: ContractConfig = env
-            .storage()
-            .instance()
-            .get(&DataKey::Config)
-            .expect("Contract not initialized");
-        
-        if admin != config.admin {
-            panic!("Unauthorized: not admin");
+        Self::require_admin(&env, &admin);
+
+        if rules.quorum_bps == 0 || rules.quorum_bps > 10_000 {
+            panic!("Invalid termination quorum");
         }
-        
-        config.vc_stake_required = new_amount;
-        env.storage().instance().set(&DataKey::Config, &config);
+
+        env.storage().instance().set(&TerminationKey::Rules, &rules);
     }
-    
+
     /// Update minimum vote balance (admin only)
     pub fn update_min_vote_balance(env: Env, admin: Address, new_amount: i128) {
         admin.require_auth();
@@ -597,6 +5405,7 @@ impl DeCoV2 {
         if admin != config.admin {
             panic!("Unauthorized: not admin");
         }
+        Self::require_not_shutdown(&env);
         
         config.min_vote_balance = new_amount;
         env.storage().instance().set(&DataKey::Config, &config);
@@ -635,8 +5444,39 @@ impl DeCoV2 {
             .unwrap_or(Vec::new(&env))
     }
     
+    /// Whether the voter voted on the founder's current application
     pub fn has_voted(env: Env, voter: Address, founder: Address) -> bool {
-        env.storage().instance().has(&DataKey::Vote(voter, founder))
+        match Self::get_startup_status(env.clone(), founder) {
+            Some(startup_data) => env
+                .storage()
+                .instance()
+                .has(&DataKey::ApplicationVote(startup_data.application_id, voter)),
+            None => false,
+        }
+    }
+
+    pub fn get_application(env: Env, application_id: u64) -> Option<ApplicationRecord> {
+        env.storage().instance().get(&DataKey::Application(application_id))
+    }
+
+    pub fn get_founder_applications(env: Env, founder: Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::FounderApplications(founder))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_application_vote(env: Env, application_id: u64, voter: Address) -> Option<bool> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApplicationVote(application_id, voter))
+    }
+
+    pub fn get_reapply_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReapplyCooldown)
+            .unwrap_or(0)
     }
     
     pub fn is_vc(env: Env, vc_address: Address) -> bool {
@@ -649,4 +5489,541 @@ impl DeCoV2 {
             .get(&DataKey::Investment(vc_address, founder))
             .unwrap_or(0)
     }
+
+    /// Aggregated view of a startup for `viewer`
+    pub fn get_startup_details(env: Env, founder: Address, viewer: Address) -> StartupDetails {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder.clone()))
+            .expect("Startup not found");
+        let config = Self::get_config(env.clone());
+        let status = Self::current_outcome(&env, &startup_data);
+        let now = env.ledger().timestamp();
+
+        let funding_progress_bps = if startup_data.funding_goal > 0 {
+            (Self::checked_mul(startup_data.total_allocated, 10_000) / startup_data.funding_goal) as u32
+        } else {
+            0
+        };
+
+        let frozen = Self::get_open_dispute(env.clone(), founder.clone()).is_some();
+        let tranche = Self::checked_mul(startup_data.total_allocated, 25) / 100;
+        let mut remaining = Self::available_escrow(&env, &startup_data);
+        let mut milestones = Vec::new(&env);
+        for index in 0..startup_data.milestone_count {
+            let unlocked = index < startup_data.current_milestone;
+            // Past milestones show what they released; later ones are
+            // projected from the escrow left, as milestone_tranche would
+            let amount = if unlocked {
+                env.storage()
+                    .instance()
+                    .get(&DeadlineKey::Tranche(startup_data.application_id, index))
+                    .unwrap_or(0)
+            } else if index + 1 >= startup_data.milestone_count {
+                remaining
+            } else {
+                tranche.min(remaining)
+            };
+            if !unlocked {
+                remaining = Self::checked_sub(remaining, amount);
+            }
+            let steps = index.saturating_sub(startup_data.current_milestone) as u64 + 1;
+            let eligible_at = startup_data
+                .last_milestone_time
+                .saturating_add(config.milestone_interval.saturating_mul(steps));
+            let kpi_gated = env
+                .storage()
+                .instance()
+                .has(&OracleKey::Kpi(startup_data.application_id, index));
+
+            milestones.push_back(MilestoneInfo {
+                index,
+                amount,
+                unlocked,
+                eligible_at: if unlocked { 0 } else { eligible_at },
+                kpi_gated,
+                eligible: index == startup_data.current_milestone
+                    && status == ApplicationOutcome::Approved
+                    && !frozen
+                    && !kpi_gated
+                    && now >= eligible_at,
+            });
+        }
+
+        StartupDetails {
+            status,
+            funding_progress_bps,
+            voting_time_remaining: startup_data.voting_end_time.saturating_sub(now),
+            viewer_vote: Self::get_application_vote(env.clone(), startup_data.application_id, viewer.clone()),
+            viewer_investment: Self::get_vc_investment(env.clone(), viewer, founder),
+            milestones,
+            startup: startup_data,
+        }
+    }
+
+    /// Startups the investor holds a position in, from index entries
+    /// `start..start + limit`. Closed positions are skipped, so a page may
+    /// hold fewer than `limit` entries.
+    pub fn get_vc_portfolio(env: Env, vc_address: Address, start: u32, limit: u32) -> Vec<PortfolioEntry> {
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&IndexKey::PortfolioLen(vc_address.clone()))
+            .unwrap_or(0);
+
+        let mut page = Vec::new(&env);
+        let end = start.saturating_add(limit).min(len);
+        for i in start..end {
+            let founder: Address = env
+                .storage()
+                .persistent()
+                .get(&IndexKey::Portfolio(vc_address.clone(), i))
+                .expect("Index entry not found");
+            let amount = Self::get_vc_investment(env.clone(), vc_address.clone(), founder.clone());
+            if amount <= 0 {
+                continue;
+            }
+
+            let startup_data: StartupData = env
+                .storage()
+                .instance()
+                .get(&DataKey::Startup(founder.clone()))
+                .expect("Startup not found");
+
+            page.push_back(PortfolioEntry {
+                startup: founder,
+                amount,
+                status: Self::current_outcome(&env, &startup_data),
+            });
+        }
+
+        page
+    }
+
+    /// Investors holding a position in the startup, from index entries
+    /// `start..start + limit`. Closed positions are skipped.
+    pub fn get_startup_investors(env: Env, founder: Address, start: u32, limit: u32) -> Vec<InvestorEntry> {
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&IndexKey::InvestorsLen(founder.clone()))
+            .unwrap_or(0);
+
+        let mut page = Vec::new(&env);
+        let end = start.saturating_add(limit).min(len);
+        for i in start..end {
+            let investor: Address = env
+                .storage()
+                .persistent()
+                .get(&IndexKey::Investors(founder.clone(), i))
+                .expect("Index entry not found");
+            let amount = Self::get_vc_investment(env.clone(), investor.clone(), founder.clone());
+            if amount > 0 {
+                page.push_back(InvestorEntry { investor, amount });
+            }
+        }
+
+        page
+    }
+
+    pub fn get_investment_limits(env: Env) -> InvestmentLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::InvestmentLimits)
+            .unwrap_or(InvestmentLimits {
+                overfunding_bps: 0,
+                min_ticket: 0,
+                max_ticket: 0,
+                max_investors: 0,
+            })
+    }
+
+    pub fn get_funding_bounds(env: Env) -> FundingBounds {
+        env.storage()
+            .instance()
+            .get(&DataKey::FundingBounds)
+            .unwrap_or(FundingBounds {
+                min_funding_goal: 1,
+                max_funding_goal: 0,
+            })
+    }
+
+    /// Metadata revisions of the founder's current application
+    pub fn get_metadata_history(env: Env, founder: Address) -> Vec<MetadataRevision> {
+        match Self::get_startup_status(env.clone(), founder) {
+            Some(startup_data) => env
+                .storage()
+                .instance()
+                .get(&DataKey::MetadataHistory(startup_data.application_id))
+                .unwrap_or(Vec::new(&env)),
+            None => Vec::new(&env),
+        }
+    }
+
+    pub fn get_startup_by_id(env: Env, startup_id: u64) -> Option<Address> {
+        env.storage().instance().get(&DataKey::StartupById(startup_id))
+    }
+
+    pub fn get_team(env: Env, founder: Address) -> Option<Team> {
+        env.storage().instance().get(&DataKey::Team(founder))
+    }
+
+    pub fn get_founder_key(env: Env, founder: Address) -> Address {
+        Self::founder_key(&env, &founder)
+    }
+
+    pub fn get_fee_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::FeeToken)
+    }
+
+    pub fn get_platform_fee_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFeeBps)
+            .unwrap_or(0)
+    }
+
+    pub fn get_treasury_balance(env: Env, xlm_token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury(xlm_token))
+            .unwrap_or(0)
+    }
+
+    pub fn get_reward_pool_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardPoolBps)
+            .unwrap_or(0)
+    }
+
+    pub fn get_voter_stats(env: Env, voter: Address) -> VoterStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoterStats(voter))
+            .unwrap_or(VoterStats {
+                reputation: 0,
+                correct_calls: 0,
+                incorrect_calls: 0,
+            })
+    }
+
+    /// Claimable voter rewards, in the fee token
+    pub fn voter_rewards(env: Env, voter: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VoterRewards(voter))
+            .unwrap_or(0)
+    }
+
+    pub fn get_min_reviews(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinReviews)
+            .unwrap_or(0)
+    }
+
+    /// Reviews of the founder's current application
+    pub fn get_reviews(env: Env, founder: Address) -> Vec<ReviewRecord> {
+        let mut reviews = Vec::new(&env);
+
+        if let Some(startup_data) = Self::get_startup_status(env.clone(), founder) {
+            let reviewers: Vec<Address> = env
+                .storage()
+                .instance()
+                .get(&DataKey::Reviewers(startup_data.application_id))
+                .unwrap_or(Vec::new(&env));
+
+            for reviewer in reviewers.iter() {
+                if let Some(review) = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Review(startup_data.application_id, reviewer))
+                {
+                    reviews.push_back(review);
+                }
+            }
+        }
+        reviews
+    }
+
+    pub fn get_review_summary(env: Env, founder: Address) -> ReviewSummary {
+        let mut summary = ReviewSummary {
+            review_count: 0,
+            total_score: 0,
+            average_score: 0,
+        };
+
+        for review in Self::get_reviews(env.clone(), founder).iter() {
+            summary.review_count += 1;
+            summary.total_score += review.score;
+        }
+
+        summary.average_score = summary
+            .total_score
+            .checked_div(summary.review_count)
+            .unwrap_or(0);
+        summary
+    }
+
+    /// Without a tier config every VC has full Lead rights
+    pub fn get_tier_config(env: Env) -> TierConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::TierConfig)
+            .unwrap_or(TierConfig {
+                seed_fund_stake: 0,
+                lead_stake: 0,
+                angel_max_ticket: 0,
+                seed_fund_max_ticket: 0,
+                lead_max_ticket: 0,
+            })
+    }
+
+    pub fn get_vc_tier(env: Env, vc_address: Address) -> VCTier {
+        let vc_data: VCData = env
+            .storage()
+            .instance()
+            .get(&DataKey::VCData(vc_address))
+            .expect("Not a VC");
+
+        Self::tier_for_stake(&env, vc_data.stake_amount)
+    }
+
+    pub fn is_kyc_attester(env: Env, attester: Address) -> bool {
+        env.storage().instance().has(&DataKey::KycAttester(attester))
+    }
+
+    pub fn get_syndicate(env: Env, founder: Address, lead: Address) -> Option<Syndicate> {
+        env.storage().instance().get(&DataKey::Syndicate(founder, lead))
+    }
+
+    pub fn get_syndicates(env: Env, founder: Address) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::SyndicateLeads(founder))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_syndicate_contribution(env: Env, founder: Address, member: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SyndicateContribution(founder, member))
+            .unwrap_or(0)
+    }
+
+    pub fn get_community_user_cap(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CommunityUserCap)
+            .unwrap_or(0)
+    }
+
+    /// Amount a user has put into the founder's current community round
+    pub fn get_community_contribution(env: Env, founder: Address, investor: Address) -> i128 {
+        match Self::get_startup_status(env.clone(), founder) {
+            Some(startup_data) => env
+                .storage()
+                .instance()
+                .get(&DataKey::CommunityContribution(startup_data.application_id, investor))
+                .unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    pub fn get_termination_rules(env: Env) -> TerminationRules {
+        env.storage()
+            .instance()
+            .get(&TerminationKey::Rules)
+            .unwrap_or(TerminationRules {
+                quorum_bps: 5_000,
+                voting_period: 7 * 24 * 60 * 60,
+            })
+    }
+
+    pub fn get_termination_proposal(env: Env, founder: Address) -> Option<TerminationProposal> {
+        let startup_data: StartupData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Startup(founder))?;
+
+        env.storage()
+            .instance()
+            .get(&TerminationKey::Proposal(startup_data.application_id))
+    }
+
+    pub fn is_allowlist_required(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&EligibilityKey::AllowlistRequired)
+            .unwrap_or(false)
+    }
+
+    pub fn get_voter_root(env: Env, application_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&EligibilityKey::VoterRoot(application_id))
+    }
+
+    /// Whether the voter has already proven allowlist membership for the round
+    pub fn is_eligible_voter(env: Env, application_id: u64, voter: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&EligibilityKey::Eligible(application_id, voter))
+    }
+
+    pub fn get_conflict_rules(env: Env) -> ConflictRules {
+        env.storage()
+            .instance()
+            .get(&ConflictKey::ConflictRules)
+            .unwrap_or(ConflictRules {
+                bar_team: true,
+                investor_policy: InvestorVotePolicy::Barred,
+            })
+    }
+
+    pub fn is_related_party(env: Env, founder: Address, party: Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&ConflictKey::RelatedParty(founder, party))
+    }
+
+    pub fn get_investor_tally(env: Env, founder: Address) -> InvestorTally {
+        let application_id = Self::get_startup_status(env.clone(), founder)
+            .expect("Startup not found")
+            .application_id;
+
+        env.storage()
+            .instance()
+            .get(&ConflictKey::InvestorTally(application_id))
+            .unwrap_or(InvestorTally { yes_votes: 0, no_votes: 0 })
+    }
+
+    pub fn get_deadline_rules(env: Env) -> DeadlineRules {
+        env.storage()
+            .instance()
+            .get(&DeadlineKey::DeadlineRules)
+            .unwrap_or(DeadlineRules {
+                deadline: 0,
+                grace_period: 0,
+                penalty: MissedMilestonePenalty::AtRisk,
+                return_bps: 0,
+            })
+    }
+
+    pub fn is_oracle(env: Env, oracle: Address) -> bool {
+        env.storage().instance().has(&OracleKey::Oracle(oracle))
+    }
+
+    /// Defaults to the VC stake
+    pub fn get_arbiter_stake_required(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DisputeKey::ArbiterStake)
+            .unwrap_or_else(|| Self::get_config(env.clone()).vc_stake_required)
+    }
+
+    pub fn get_dispute_voting_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DisputeKey::VotingPeriod)
+            .unwrap_or(7 * 24 * 60 * 60)
+    }
+
+    pub fn get_dispute_min_share(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DisputeKey::MinOpenerShare)
+            .unwrap_or(1_000)
+    }
+
+    pub fn get_arbiter(env: Env, arbiter: Address) -> Option<ArbiterData> {
+        env.storage().instance().get(&DisputeKey::Arbiter(arbiter))
+    }
+
+    pub fn get_arbiters(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DisputeKey::Arbiters)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Option<Dispute> {
+        env.storage().instance().get(&DisputeKey::Dispute(dispute_id))
+    }
+
+    /// Id of the founder's unresolved dispute, if any
+    pub fn get_open_dispute(env: Env, founder: Address) -> Option<u64> {
+        env.storage().instance().get(&DisputeKey::Open(founder))
+    }
+
+    pub fn get_revenue_pool(env: Env, founder: Address) -> Option<RevenuePool> {
+        env.storage().instance().get(&RevenueKey::Pool(founder))
+    }
+
+    /// Revenue accrued to an investor since their last settlement
+    pub fn pending_revenue(env: Env, investor: Address, founder: Address) -> i128 {
+        let units: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Investment(investor.clone(), founder.clone()))
+            .unwrap_or(0);
+        let debt: i128 = env
+            .storage()
+            .instance()
+            .get(&RevenueKey::Debt(founder.clone(), investor))
+            .unwrap_or(0);
+        let pool = Self::load_revenue_pool(&env, &founder);
+
+        Self::checked_sub(Self::checked_mul(units, pool.acc_per_unit) / REVENUE_PRECISION, debt)
+    }
+
+    /// Revenue an investor can claim right now, before any syndicate carry
+    pub fn get_claimable_revenue(env: Env, investor: Address, founder: Address) -> i128 {
+        let owed: i128 = env
+            .storage()
+            .instance()
+            .get(&RevenueKey::Owed(founder.clone(), investor.clone()))
+            .unwrap_or(0);
+
+        Self::checked_add(owed, Self::pending_revenue(env, investor, founder))
+    }
+
+    pub fn get_share_terms(env: Env, founder: Address) -> Option<ShareTerms> {
+        env.storage().instance().get(&DataKey::ShareTerms(founder))
+    }
+
+    pub fn get_shares(env: Env, founder: Address, holder: Address) -> i128 {
+        match Self::get_share_terms(env.clone(), founder) {
+            Some(terms) => token::Client::new(&env, &terms.token).balance(&holder),
+            None => 0,
+        }
+    }
+
+    /// Cap table: live share balances of every holder the contract has issued
+    /// or moved shares to, paginated like `get_startup_investors`
+    pub fn get_cap_table(env: Env, founder: Address, start: u32, limit: u32) -> Vec<CapTableEntry> {
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&IndexKey::CapTableLen(founder.clone()))
+            .unwrap_or(0);
+
+        let mut page = Vec::new(&env);
+        let end = start.saturating_add(limit).min(len);
+        for i in start..end {
+            let holder: Address = env
+                .storage()
+                .persistent()
+                .get(&IndexKey::CapTable(founder.clone(), i))
+                .expect("Index entry not found");
+            let shares = Self::get_shares(env.clone(), founder.clone(), holder.clone());
+            if shares > 0 {
+                page.push_back(CapTableEntry { holder, shares });
+            }
+        }
+
+        page
+    }
 }
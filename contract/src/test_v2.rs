@@ -0,0 +1,2266 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient},
+    Address, BytesN, Env, String, Symbol, Vec,
+};
+
+const VC_STAKE: i128 = 10_000_000_000;
+const MIN_VOTE_BALANCE: i128 = 1_000_000_000;
+const MILESTONE_INTERVAL: u64 = 30 * 24 * 60 * 60;
+
+struct Setup<'a> {
+    env: Env,
+    client: DeCoV2Client<'a>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, DeCoV2);
+    let client = DeCoV2Client::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    client.init(&admin, &token, &0, &VC_STAKE, &MIN_VOTE_BALANCE, &MILESTONE_INTERVAL);
+
+    Setup { env, client, admin, token }
+}
+
+fn mint(s: &Setup, to: &Address, amount: i128) {
+    StellarAssetClient::new(&s.env, &s.token).mint(to, &amount);
+}
+
+fn balance(s: &Setup, of: &Address) -> i128 {
+    TokenClient::new(&s.env, &s.token).balance(of)
+}
+
+fn new_vc(s: &Setup, funds: i128) -> Address {
+    let vc = Address::generate(&s.env);
+    mint(s, &vc, VC_STAKE + funds);
+    s.client
+        .stake_to_become_vc(&vc, &String::from_str(&s.env, "Fund"), &s.token);
+    vc
+}
+
+/// Stellar asset whose admin is the contract, ready to carry a startup's shares
+fn share_token(s: &Setup) -> Address {
+    s.env
+        .register_stellar_asset_contract_v2(s.client.address.clone())
+        .address()
+}
+
+fn approved_startup(s: &Setup, funding_goal: i128) -> Address {
+    let founder = Address::generate(&s.env);
+    s.client.apply(
+        &founder,
+        &String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        &funding_goal,
+    );
+    s.client.approve_application(&s.admin, &founder);
+    founder
+}
+
+fn advance_time(s: &Setup, seconds: u64) {
+    s.env.ledger().with_mut(|li| li.timestamp += seconds);
+}
+
+#[test]
+fn test_claiming_stays_open_when_investing_paused() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    s.client.set_paused(&s.admin, &PauseFlag::Investing, &true);
+    assert!(s.client.is_paused(&PauseFlag::Investing));
+    assert!(!s.client.is_paused(&PauseFlag::Claiming));
+
+    s.client.claim_funds(&founder, &s.token);
+    assert_eq!(balance(&s, &founder), 250);
+}
+
+#[test]
+#[should_panic(expected = "Function is paused")]
+fn test_paused_function_rejected() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.set_paused(&s.admin, &PauseFlag::Investing, &true);
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+}
+
+#[test]
+fn test_emergency_shutdown_withdrawals() {
+    let s = setup();
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    s.client.emergency_shutdown(&s.admin);
+    assert!(s.client.is_shutdown());
+
+    // 3,000 of escrow remains, split 3:1
+    assert_eq!(s.client.emergency_withdraw_investor(&vc1, &founder), 2_250);
+    assert_eq!(s.client.emergency_withdraw_investor(&vc2, &founder), 750);
+    assert_eq!(s.client.emergency_withdraw_founder(&founder), 1_000);
+    assert_eq!(s.client.emergency_withdraw_stake(&vc1), VC_STAKE);
+    assert_eq!(s.client.emergency_withdraw_stake(&vc2), VC_STAKE);
+
+    assert_eq!(balance(&s, &vc1), VC_STAKE + 2_250);
+    assert_eq!(balance(&s, &vc2), VC_STAKE + 750);
+    assert_eq!(balance(&s, &founder), 1_000);
+    assert_eq!(balance(&s, &s.client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Nothing to withdraw")]
+fn test_emergency_withdraw_only_once() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    s.client.emergency_shutdown(&s.admin);
+
+    s.client.emergency_withdraw_investor(&vc, &founder);
+    s.client.emergency_withdraw_investor(&vc, &founder);
+}
+
+#[test]
+#[should_panic(expected = "Contract is shut down")]
+fn test_shutdown_blocks_normal_operations() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.emergency_shutdown(&s.admin);
+    s.client.claim_funds(&founder, &s.token);
+}
+
+#[test]
+#[should_panic(expected = "Contract is shut down")]
+fn test_shutdown_freezes_admin_settings() {
+    let s = setup();
+
+    s.client.emergency_shutdown(&s.admin);
+    s.client.update_min_vote_balance(&s.admin, &0);
+}
+
+#[test]
+fn test_deposits_only_in_config_token() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 5_000);
+
+    let junk = s.env.register_stellar_asset_contract_v2(Address::generate(&s.env)).address();
+    StellarAssetClient::new(&s.env, &junk).mint(&vc, &(VC_STAKE + 1_000));
+
+    let other = Address::generate(&s.env);
+    assert!(s
+        .client
+        .try_stake_to_become_vc(&other, &String::from_str(&s.env, "Fund"), &junk)
+        .is_err());
+    assert!(s.client.try_vc_invest(&vc, &founder, &1_000, &junk).is_err());
+    assert!(s.client.try_claim_funds(&founder, &junk).is_err());
+    assert_eq!(s.client.get_vc_investment(&vc, &founder), 0);
+}
+
+#[test]
+fn test_shares_issued_at_founder_valuation() {
+    let s = setup();
+    let vc1 = new_vc(&s, 2_000);
+    let vc2 = new_vc(&s, 500);
+    let founder = approved_startup(&s, 5_000);
+
+    // 1,000,000 founder shares at a 10,000 valuation = 100 shares per token
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+
+    s.client.vc_invest(&vc1, &founder, &2_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &500, &s.token);
+
+    assert_eq!(s.client.get_shares(&founder, &vc1), 200_000);
+    assert_eq!(s.client.get_shares(&founder, &vc2), 50_000);
+    assert_eq!(s.client.get_share_terms(&founder).unwrap().total_shares, 1_250_000);
+
+    // Shares are a real token that holders can move themselves
+    let terms = s.client.get_share_terms(&founder).unwrap();
+    assert_eq!(TokenClient::new(&s.env, &terms.token).balance(&vc1), 200_000);
+
+    let holder = Address::generate(&s.env);
+    s.client.transfer_shares(&vc1, &holder, &founder, &20_000);
+    TokenClient::new(&s.env, &terms.token).transfer(&vc2, &holder, &10_000);
+
+    let cap_table = s.client.get_cap_table(&founder, &0, &10);
+    assert_eq!(cap_table.len(), 4);
+    assert_eq!(cap_table.get(1).unwrap().shares, 180_000);
+    assert_eq!(cap_table.get(3).unwrap().holder, holder);
+    assert_eq!(cap_table.get(3).unwrap().shares, 30_000);
+    assert_eq!(s.client.get_cap_table(&founder, &1, &1).get(0).unwrap().holder, vc1);
+}
+
+#[test]
+fn test_share_terms_need_contract_administered_token() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let foreign = s.env.register_stellar_asset_contract_v2(founder.clone()).address();
+
+    assert!(s
+        .client
+        .try_set_share_terms(&founder, &10_000, &1_000_000, &foreign)
+        .is_err());
+}
+
+#[test]
+fn test_share_terms_locked_for_later_rounds() {
+    let s = setup();
+    let vc = new_vc(&s, 2_000);
+    let founder = approved_startup(&s, 1_000);
+
+    // Terms may be replaced while only the founder holds shares
+    s.client.set_share_terms(&founder, &20_000, &1_000_000, &share_token(&s));
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+    assert_eq!(s.client.get_share_terms(&founder).unwrap().total_shares, 1_000_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    for _ in 0..4 {
+        advance_time(&s, MILESTONE_INTERVAL);
+        s.client.unlock_milestone(&s.admin, &founder);
+    }
+    s.client.claim_funds(&founder, &s.token);
+    apply_as(&s, &founder, 1_000);
+    s.client.approve_application(&s.admin, &founder);
+
+    assert!(s
+        .client
+        .try_set_share_terms(&founder, &1, &1_000_000_000, &share_token(&s))
+        .is_err());
+
+    // The next round buys in at the same price
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    assert_eq!(s.client.get_shares(&founder, &vc), 200_000);
+    assert_eq!(s.client.get_shares(&founder, &founder), 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Share terms locked after first investment")]
+fn test_share_terms_locked_after_investment() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    s.client.set_share_terms(&founder, &20_000, &1_000_000, &share_token(&s));
+}
+
+#[test]
+fn test_invest_capped_at_funding_goal() {
+    let s = setup();
+    let vc1 = new_vc(&s, 800);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    assert_eq!(s.client.vc_invest(&vc1, &founder, &800, &s.token), 800);
+    // Only 200 is left; the excess stays with the VC
+    assert_eq!(s.client.vc_invest(&vc2, &founder, &1_000, &s.token), 200);
+    assert_eq!(balance(&s, &vc2), 800);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().total_allocated, 1_000);
+}
+
+#[test]
+fn test_invest_overfunding_allowance() {
+    let s = setup();
+    let vc = new_vc(&s, 2_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.update_investment_limits(
+        &s.admin,
+        &InvestmentLimits {
+            overfunding_bps: 2_000,
+            min_ticket: 0,
+            max_ticket: 0,
+            max_investors: 0,
+        },
+    );
+
+    assert_eq!(s.client.vc_invest(&vc, &founder, &2_000, &s.token), 1_200);
+}
+
+#[test]
+#[should_panic(expected = "Amount must be positive")]
+fn test_invest_rejects_negative_amount() {
+    let s = setup();
+    let vc = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &-100, &s.token);
+}
+
+#[test]
+#[should_panic(expected = "Above maximum ticket")]
+fn test_invest_max_ticket() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.update_investment_limits(
+        &s.admin,
+        &InvestmentLimits {
+            overfunding_bps: 0,
+            min_ticket: 100,
+            max_ticket: 500,
+            max_investors: 0,
+        },
+    );
+
+    s.client.vc_invest(&vc, &founder, &400, &s.token);
+    s.client.vc_invest(&vc, &founder, &200, &s.token);
+}
+
+#[test]
+#[should_panic(expected = "Investor limit reached")]
+fn test_invest_max_investors() {
+    let s = setup();
+    let vc1 = new_vc(&s, 100);
+    let vc2 = new_vc(&s, 100);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.update_investment_limits(
+        &s.admin,
+        &InvestmentLimits {
+            overfunding_bps: 0,
+            min_ticket: 0,
+            max_ticket: 0,
+            max_investors: 1,
+        },
+    );
+
+    s.client.vc_invest(&vc1, &founder, &100, &s.token);
+    s.client.vc_invest(&vc2, &founder, &100, &s.token);
+}
+
+#[test]
+#[should_panic(expected = "Invalid IPFS CID")]
+fn test_apply_rejects_empty_cid() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+
+    s.client.apply(&founder, &String::from_str(&s.env, ""), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid IPFS CID")]
+fn test_apply_rejects_malformed_cidv0() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+
+    // '0' is not a base58 character
+    s.client.apply(
+        &founder,
+        &String::from_str(&s.env, "Qm0wAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        &1_000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Funding goal out of bounds")]
+fn test_apply_rejects_funding_goal_outside_bounds() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+
+    s.client.update_funding_bounds(
+        &s.admin,
+        &FundingBounds {
+            min_funding_goal: 1_000,
+            max_funding_goal: 10_000,
+        },
+    );
+
+    s.client.apply(
+        &founder,
+        &String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        &20_000,
+    );
+}
+
+#[test]
+fn test_update_application_metadata_keeps_history() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    let v0 = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    let v1 = String::from_str(
+        &s.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    s.client.apply(&founder, &v0, &1_000);
+    advance_time(&s, 60);
+    s.client.update_application_metadata(&founder, &v1);
+
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().ipfs_cid, v1);
+
+    let history = s.client.get_metadata_history(&founder);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().ipfs_cid, v0);
+    assert_eq!(history.get(1).unwrap().ipfs_cid, v1);
+    assert_eq!(history.get(1).unwrap().updated_at, 60);
+}
+
+#[test]
+#[should_panic(expected = "CID already uploaded")]
+fn test_update_application_metadata_rejects_duplicate_cid() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    let v0 = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    let v1 = String::from_str(
+        &s.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+
+    s.client.apply(&founder, &v0, &1_000);
+    s.client.update_application_metadata(&founder, &v1);
+    s.client.update_application_metadata(&founder, &v0);
+}
+
+#[test]
+#[should_panic(expected = "Voting period has ended")]
+fn test_update_application_metadata_after_voting() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.update_application_metadata(
+        &founder,
+        &String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Voting period has ended")]
+fn test_update_application_metadata_after_rejection() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    s.client.reject_application(&s.admin, &founder);
+
+    s.client.update_application_metadata(
+        &founder,
+        &String::from_str(&s.env, "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+    );
+}
+
+fn team_of(s: &Setup, founder: &Address, members: &[Address], bps: &[u32], threshold: u32) {
+    s.client.set_team(
+        founder,
+        &Vec::from_slice(&s.env, members),
+        &Vec::from_slice(&s.env, bps),
+        &threshold,
+    );
+}
+
+#[test]
+fn test_startup_ids_assigned_in_order() {
+    let s = setup();
+    let first = approved_startup(&s, 1_000);
+    let second = approved_startup(&s, 1_000);
+
+    assert_eq!(s.client.get_startup_status(&second).unwrap().startup_id, 1);
+    assert_eq!(s.client.get_startup_by_id(&0), Some(first));
+    assert_eq!(s.client.get_startup_by_id(&1), Some(second));
+}
+
+#[test]
+fn test_team_claim_split_by_bps() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    let c = Address::generate(&s.env);
+
+    team_of(&s, &founder, &[a.clone(), b.clone(), c.clone()], &[5_000, 3_000, 2_000], 2);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    let signers = Vec::from_slice(&s.env, &[a.clone(), c.clone()]);
+    assert_eq!(s.client.claim_funds_team(&founder, &signers), 250);
+
+    assert_eq!(balance(&s, &a), 125);
+    assert_eq!(balance(&s, &b), 75);
+    assert_eq!(balance(&s, &c), 50);
+}
+
+#[test]
+#[should_panic(expected = "Not enough team signatures")]
+fn test_team_claim_below_threshold() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+
+    team_of(&s, &founder, &[a.clone(), b.clone()], &[5_000, 5_000], 2);
+
+    s.client
+        .claim_funds_team(&founder, &Vec::from_slice(&s.env, &[a]));
+}
+
+#[test]
+#[should_panic(expected = "Startup is team-owned")]
+fn test_founder_key_cannot_claim_for_team() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let a = Address::generate(&s.env);
+
+    team_of(&s, &founder, &[a], &[10_000], 1);
+
+    s.client.claim_funds(&founder, &s.token);
+}
+
+#[test]
+fn test_rotated_founder_key_acts_for_startup() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    let new_key = Address::generate(&s.env);
+
+    s.client.rotate_founder(&founder, &Vec::new(&s.env), &new_key);
+    assert_eq!(s.client.get_founder_key(&founder), new_key);
+
+    s.client.set_payback_cap(&founder, &20_000);
+    assert_eq!(s.env.auths()[0].0, new_key);
+
+    // Payouts go to the new key, not the old founder address
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    s.client.claim_funds(&founder, &s.token);
+    assert_eq!(balance(&s, &new_key), 250);
+    assert_eq!(balance(&s, &founder), 0);
+}
+
+#[test]
+fn test_team_rotates_founder_key() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    let new_key = Address::generate(&s.env);
+    team_of(&s, &founder, &[a.clone(), b.clone()], &[5_000, 5_000], 2);
+
+    assert!(s
+        .client
+        .try_rotate_founder(&founder, &Vec::from_array(&s.env, [a.clone()]), &new_key)
+        .is_err());
+    assert!(s.client.try_recover_founder_key(&s.admin, &founder, &new_key).is_err());
+
+    s.client.rotate_founder(&founder, &Vec::from_slice(&s.env, &[a, b]), &new_key);
+    assert_eq!(s.client.get_founder_key(&founder), new_key);
+}
+
+#[test]
+fn test_admin_recovers_lost_founder_key() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let new_key = Address::generate(&s.env);
+
+    s.client.recover_founder_key(&s.admin, &founder, &new_key);
+    team_of(&s, &founder, core::slice::from_ref(&new_key), &[10_000], 1);
+    assert_eq!(s.env.auths()[0].0, new_key);
+}
+
+#[test]
+fn test_rotate_team_member_keeps_share() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let a = Address::generate(&s.env);
+    let b = Address::generate(&s.env);
+    let lost = Address::generate(&s.env);
+    let replacement = Address::generate(&s.env);
+
+    team_of(&s, &founder, &[a.clone(), b.clone(), lost.clone()], &[4_000, 4_000, 2_000], 2);
+
+    s.client.rotate_team_member(
+        &founder,
+        &Vec::from_slice(&s.env, &[a, b]),
+        &lost,
+        &replacement,
+    );
+
+    let team = s.client.get_team(&founder).unwrap();
+    assert_eq!(team.members.get(2).unwrap(), replacement);
+    assert_eq!(team.payout_bps.get(2).unwrap(), 2_000);
+}
+
+fn apply_as(s: &Setup, founder: &Address, funding_goal: i128) {
+    s.client.apply(
+        founder,
+        &String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        &funding_goal,
+    );
+}
+
+#[test]
+fn test_reapply_after_rejection() {
+    let s = setup();
+    let voter = Address::generate(&s.env);
+    let founder = Address::generate(&s.env);
+    mint(&s, &voter, MIN_VOTE_BALANCE);
+
+    apply_as(&s, &founder, 1_000);
+    s.client.vote(&voter, &founder, &false, &Vec::new(&s.env), &s.token);
+    s.client.reject_application(&s.admin, &founder);
+
+    s.client.update_reapply_cooldown(&s.admin, &3_600);
+    advance_time(&s, 3_600);
+    apply_as(&s, &founder, 2_000);
+
+    let status = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(status.application_id, 1);
+    assert_eq!(status.startup_id, 0);
+    assert_eq!(status.no_votes, 0);
+    assert!(!s.client.has_voted(&voter, &founder));
+    assert_eq!(s.client.get_all_startups().len(), 1);
+
+    // The first round stays queryable
+    let first = s.client.get_application(&0).unwrap();
+    assert_eq!(first.outcome, ApplicationOutcome::Rejected);
+    assert_eq!(first.no_votes, 1);
+    assert_eq!(s.client.get_application_vote(&0, &voter), Some(false));
+    assert_eq!(s.client.get_founder_applications(&founder).len(), 2);
+
+    s.client.vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token);
+    assert!(s.client.has_voted(&voter, &founder));
+}
+
+#[test]
+#[should_panic(expected = "Reapply cooldown active")]
+fn test_reapply_cooldown() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+
+    s.client.update_reapply_cooldown(&s.admin, &3_600);
+    apply_as(&s, &founder, 1_000);
+    s.client.reject_application(&s.admin, &founder);
+    apply_as(&s, &founder, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Already applied")]
+fn test_cannot_reapply_while_funded() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    apply_as(&s, &founder, 1_000);
+}
+
+#[test]
+fn test_reapply_after_completion() {
+    let s = setup();
+    let vc = new_vc(&s, 2_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    for _ in 0..4 {
+        advance_time(&s, MILESTONE_INTERVAL);
+        s.client.unlock_milestone(&s.admin, &founder);
+    }
+    s.client.claim_funds(&founder, &s.token);
+    assert_eq!(s.client.get_application(&0).unwrap().outcome, ApplicationOutcome::Completed);
+
+    apply_as(&s, &founder, 1_000);
+    s.client.approve_application(&s.admin, &founder);
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+
+    let status = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(status.total_allocated, 1_000);
+    assert_eq!(status.investor_count, 1);
+    assert_eq!(s.client.get_vc_investment(&vc, &founder), 2_000);
+}
+
+#[test]
+fn test_reapply_before_failed_round_refunds_are_claimed() {
+    let s = setup();
+    let vc1 = new_vc(&s, 100);
+    let vc2 = new_vc(&s, 100);
+    let vc3 = new_vc(&s, 101);
+    let founder = approved_startup(&s, 301);
+
+    s.client.vc_invest(&vc1, &founder, &100, &s.token);
+    s.client.vc_invest(&vc2, &founder, &100, &s.token);
+    s.client.vc_invest(&vc3, &founder, &101, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    s.client.claim_funds(&founder, &s.token);
+    s.client.mark_failed(&s.admin, &founder);
+    assert_eq!(s.client.claim_termination_refund(&vc1, &founder), 75);
+
+    // Rounding dust and unclaimed refunds do not hold up the next round
+    advance_time(&s, s.client.get_reapply_cooldown());
+    apply_as(&s, &founder, 1_000);
+    s.client.approve_application(&s.admin, &founder);
+    assert!(s.client.reconcile(&s.token).balanced);
+
+    // Refund rights stay with the first round's investors
+    assert!(s.client.try_transfer_position(&vc2, &vc1, &founder, &100, &0).is_err());
+    assert_eq!(s.client.claim_termination_refund(&vc2, &founder), 75);
+    assert_eq!(s.client.claim_termination_refund(&vc3, &founder), 75);
+    assert!(s.client.try_claim_termination_refund(&vc3, &founder).is_err());
+    assert!(s.client.reconcile(&s.token).balanced);
+}
+
+#[test]
+fn test_treasury_collects_fees_and_reconciles() {
+    let s = setup();
+    s.client.set_fee_token(&s.admin, &s.token);
+    s.client.update_platform_fee(&s.admin, &200);
+
+    s.client.update_application_fee(&s.admin, &50);
+
+    let vc = new_vc(&s, 1_000);
+    let founder = Address::generate(&s.env);
+    mint(&s, &founder, 50);
+    apply_as(&s, &founder, 1_000);
+    s.client.approve_application(&s.admin, &founder);
+
+    // 2% of 1,000 is kept by the platform
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().total_allocated, 980);
+    assert_eq!(s.client.get_vc_investment(&vc, &founder), 980);
+
+    s.client.slash_vc_stake(&s.admin, &vc, &100);
+    assert_eq!(s.client.get_treasury_balance(&s.token), 170);
+
+    let report = s.client.reconcile(&s.token);
+    assert_eq!(report.stakes, VC_STAKE - 100);
+    assert_eq!(report.escrowed, 980);
+    assert_eq!(report.treasury, 170);
+    assert!(report.balanced);
+
+    let payee = Address::generate(&s.env);
+    s.client.treasury_withdraw(&s.admin, &s.token, &payee, &70);
+    assert_eq!(balance(&s, &payee), 70);
+    assert!(s.client.reconcile(&s.token).balanced);
+}
+
+#[test]
+#[should_panic(expected = "Treasury spend limit exceeded")]
+fn test_treasury_spend_limit() {
+    let s = setup();
+    let vc = new_vc(&s, 0);
+
+    s.client.slash_vc_stake(&s.admin, &vc, &1_000);
+    s.client.update_treasury_spend_limit(
+        &s.admin,
+        &SpendLimit {
+            max_per_period: 500,
+            period: 86_400,
+        },
+    );
+
+    let payee = Address::generate(&s.env);
+    s.client.treasury_withdraw(&s.admin, &s.token, &payee, &400);
+    s.client.treasury_withdraw(&s.admin, &s.token, &payee, &200);
+}
+
+#[test]
+fn test_audit_totals_track_every_flow() {
+    let s = setup();
+    s.client.set_invariant_checks(&s.admin, &true);
+
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    s.client.claim_funds(&founder, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    let totals = s.client.audit_totals();
+    assert_eq!(totals.total_stakes, 2 * VC_STAKE);
+    assert_eq!(totals.total_escrow, 1_000);
+    assert_eq!(totals.total_unlocked_unclaimed, 1_000);
+    assert_eq!(totals.total_claimed, 2_000);
+    assert!(s.client.is_solvent(&s.token));
+
+    s.client.emergency_shutdown(&s.admin);
+    s.client.emergency_withdraw_investor(&vc1, &founder);
+
+    let totals = s.client.audit_totals();
+    assert_eq!(totals.total_escrow, 250);
+    assert_eq!(totals.total_refunded, 750);
+    assert!(s.client.is_solvent(&s.token));
+}
+
+#[test]
+#[should_panic(expected = "Solvency invariant violated")]
+fn test_invariant_check_catches_missing_funds() {
+    let s = setup();
+    s.client.set_invariant_checks(&s.admin, &true);
+
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+
+    // Simulate funds leaving the contract outside of its own accounting
+    TokenClient::new(&s.env, &s.token).burn(&s.client.address, &1);
+    assert!(!s.client.is_solvent(&s.token));
+
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    s.client.claim_funds(&founder, &s.token);
+}
+
+#[test]
+fn test_voters_rewarded_for_backing_successful_startup() {
+    let s = setup();
+    s.client.set_fee_token(&s.admin, &s.token);
+    s.client.update_application_fee(&s.admin, &100);
+    s.client.update_reward_pool_bps(&s.admin, &5_000);
+
+    let vc = new_vc(&s, 1_000);
+    let backer1 = Address::generate(&s.env);
+    let backer2 = Address::generate(&s.env);
+    let skeptic = Address::generate(&s.env);
+    for voter in [&backer1, &backer2, &skeptic] {
+        mint(&s, voter, MIN_VOTE_BALANCE);
+    }
+
+    let founder = Address::generate(&s.env);
+    mint(&s, &founder, 100);
+    apply_as(&s, &founder, 1_000);
+    s.client.vote(&backer1, &founder, &true, &Vec::new(&s.env), &s.token);
+    s.client.vote(&backer2, &founder, &true, &Vec::new(&s.env), &s.token);
+    s.client.vote(&skeptic, &founder, &false, &Vec::new(&s.env), &s.token);
+    s.client.approve_application(&s.admin, &founder);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    for _ in 0..4 {
+        advance_time(&s, MILESTONE_INTERVAL);
+        s.client.unlock_milestone(&s.admin, &founder);
+    }
+
+    let application_id = s.client.get_startup_status(&founder).unwrap().application_id;
+    for voter in [&backer1, &backer2, &skeptic] {
+        s.client.settle_vote(voter, &application_id);
+    }
+
+    assert_eq!(s.client.voter_rewards(&backer1), 25);
+    assert_eq!(s.client.voter_rewards(&skeptic), 0);
+    assert_eq!(s.client.get_voter_stats(&backer1).reputation, 1);
+    assert_eq!(s.client.get_voter_stats(&skeptic).reputation, -1);
+    assert!(s.client.reconcile(&s.token).balanced);
+
+    assert_eq!(s.client.claim_voter_rewards(&backer2), 25);
+    assert_eq!(balance(&s, &backer2), MIN_VOTE_BALANCE + 25);
+    assert!(s.client.reconcile(&s.token).balanced);
+}
+
+#[test]
+fn test_failed_startup_pot_returns_to_treasury() {
+    let s = setup();
+    s.client.set_fee_token(&s.admin, &s.token);
+    s.client.update_application_fee(&s.admin, &100);
+    s.client.update_reward_pool_bps(&s.admin, &5_000);
+
+    let backer = Address::generate(&s.env);
+    let skeptic = Address::generate(&s.env);
+    mint(&s, &backer, MIN_VOTE_BALANCE);
+    mint(&s, &skeptic, MIN_VOTE_BALANCE);
+
+    let founder = Address::generate(&s.env);
+    mint(&s, &founder, 100);
+    apply_as(&s, &founder, 1_000);
+    s.client.vote(&backer, &founder, &true, &Vec::new(&s.env), &s.token);
+    s.client.vote(&skeptic, &founder, &false, &Vec::new(&s.env), &s.token);
+    s.client.approve_application(&s.admin, &founder);
+    s.client.mark_failed(&s.admin, &founder);
+
+    let application_id = s.client.get_startup_status(&founder).unwrap().application_id;
+    s.client.settle_vote(&backer, &application_id);
+    s.client.settle_vote(&skeptic, &application_id);
+
+    assert_eq!(s.client.voter_rewards(&backer), 0);
+    assert_eq!(s.client.get_voter_stats(&backer).incorrect_calls, 1);
+    assert_eq!(s.client.get_voter_stats(&skeptic).correct_calls, 1);
+    assert_eq!(s.client.get_treasury_balance(&s.token), 100);
+}
+
+#[test]
+#[should_panic(expected = "Nothing to settle")]
+fn test_vote_settles_once() {
+    let s = setup();
+    let voter = Address::generate(&s.env);
+    mint(&s, &voter, MIN_VOTE_BALANCE);
+
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    s.client.vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token);
+    s.client.approve_application(&s.admin, &founder);
+    s.client.mark_failed(&s.admin, &founder);
+
+    let application_id = s.client.get_startup_status(&founder).unwrap().application_id;
+    s.client.settle_vote(&voter, &application_id);
+    s.client.settle_vote(&voter, &application_id);
+}
+
+#[test]
+#[should_panic(expected = "Startup terminated or failed")]
+fn test_failed_startup_cannot_unlock() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    s.client.mark_failed(&s.admin, &founder);
+
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+}
+
+#[test]
+fn test_failed_startup_escrow_refunded() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+    s.client.mark_failed(&s.admin, &founder);
+
+    assert_eq!(s.client.claim_termination_refund(&vc, &founder), 1_000);
+}
+
+#[test]
+fn test_final_milestone_releases_rounding_dust() {
+    let s = setup();
+    let vc = new_vc(&s, 1_003);
+    let founder = approved_startup(&s, 1_003);
+
+    s.client.vc_invest(&vc, &founder, &1_003, &s.token);
+    for _ in 0..4 {
+        advance_time(&s, MILESTONE_INTERVAL);
+        s.client.unlock_milestone(&s.admin, &founder);
+    }
+
+    let startup = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(startup.unlocked_balance, 1_003);
+
+    s.client.claim_funds(&founder, &s.token);
+    advance_time(&s, s.client.get_reapply_cooldown());
+    apply_as(&s, &founder, 1_000);
+}
+
+#[test]
+fn test_reviews_aggregate_and_gate_approval() {
+    let s = setup();
+    let vc1 = new_vc(&s, 0);
+    let vc2 = new_vc(&s, 0);
+    let founder = Address::generate(&s.env);
+    let report = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    s.client.update_min_reviews(&s.admin, &2);
+    apply_as(&s, &founder, 1_000);
+
+    s.client.submit_review(&vc1, &founder, &80, &report);
+    assert!(s.client.try_approve_application(&s.admin, &founder).is_err());
+
+    s.client.submit_review(&vc2, &founder, &65, &report);
+    s.client.approve_application(&s.admin, &founder);
+
+    let summary = s.client.get_review_summary(&founder);
+    assert_eq!(summary.review_count, 2);
+    assert_eq!(summary.total_score, 145);
+    assert_eq!(summary.average_score, 72);
+    assert_eq!(s.client.get_reviews(&founder).get(0).unwrap().reviewer, vc1);
+}
+
+#[test]
+#[should_panic(expected = "Already reviewed")]
+fn test_one_review_per_vc() {
+    let s = setup();
+    let vc = new_vc(&s, 0);
+    let founder = Address::generate(&s.env);
+    let report = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    apply_as(&s, &founder, 1_000);
+    s.client.submit_review(&vc, &founder, &80, &report);
+    s.client.submit_review(&vc, &founder, &90, &report);
+}
+
+fn tiered(s: &Setup) {
+    s.client.update_tier_config(
+        &s.admin,
+        &TierConfig {
+            seed_fund_stake: 2 * VC_STAKE,
+            lead_stake: 5 * VC_STAKE,
+            angel_max_ticket: 500,
+            seed_fund_max_ticket: 5_000,
+            lead_max_ticket: 0,
+        },
+    );
+}
+
+#[test]
+fn test_vc_tier_follows_stake() {
+    let s = setup();
+    tiered(&s);
+    let vc = new_vc(&s, 4 * VC_STAKE);
+
+    assert_eq!(s.client.get_vc_tier(&vc), VCTier::Angel);
+    s.client.top_up_stake(&vc, &VC_STAKE);
+    assert_eq!(s.client.get_vc_tier(&vc), VCTier::SeedFund);
+    s.client.top_up_stake(&vc, &(3 * VC_STAKE));
+    assert_eq!(s.client.get_vc_tier(&vc), VCTier::Lead);
+    assert_eq!(s.client.get_vc_data(&vc).unwrap().stake_amount, 5 * VC_STAKE);
+}
+
+#[test]
+#[should_panic(expected = "Above tier maximum ticket")]
+fn test_angel_ticket_limited() {
+    let s = setup();
+    tiered(&s);
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&vc, &founder, &600, &s.token);
+}
+
+#[test]
+fn test_vc_profile_and_verification() {
+    let s = setup();
+    let vc = new_vc(&s, 0);
+    let attester = Address::generate(&s.env);
+    let cid = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+
+    s.client
+        .update_vc_profile(&vc, &String::from_str(&s.env, "New Fund"), &Some(cid.clone()));
+
+    assert!(s.client.try_set_vc_verified(&attester, &vc, &true).is_err());
+    s.client.set_kyc_attester(&s.admin, &attester, &true);
+    s.client.set_vc_verified(&attester, &vc, &true);
+
+    let vc_data = s.client.get_vc_data(&vc).unwrap();
+    assert_eq!(vc_data.company_name, String::from_str(&s.env, "New Fund"));
+    assert_eq!(vc_data.metadata_cid, Some(cid));
+    assert!(vc_data.verified);
+}
+
+#[test]
+fn test_syndicate_members_invest_and_lead_earns_carry() {
+    let s = setup();
+    let lead = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 5_000);
+    let member = Address::generate(&s.env);
+    mint(&s, &member, 2_000);
+
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+    s.client.open_syndicate(&lead, &founder, &2_000, &100);
+    s.client.vc_invest(&lead, &founder, &1_000, &s.token);
+
+    assert_eq!(s.client.join_syndicate(&member, &founder, &lead, &2_000), 2_000);
+
+    // Member is not a VC but holds the position
+    assert!(!s.client.is_vc(&member));
+    assert_eq!(s.client.get_vc_investment(&member, &founder), 2_000);
+    assert_eq!(s.client.get_syndicate_contribution(&founder, &member), 2_000);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().total_allocated, 3_000);
+
+    // 20% carry on the member's 200,000 shares
+    assert_eq!(s.client.get_shares(&founder, &member), 160_000);
+    assert_eq!(s.client.get_shares(&founder, &lead), 140_000);
+
+    let syndicate = s.client.get_syndicate(&founder, &lead).unwrap();
+    assert_eq!(syndicate.total_raised, 2_000);
+    assert_eq!(syndicate.member_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "Only Lead-tier VCs can open a syndicate")]
+fn test_only_lead_tier_opens_syndicate() {
+    let s = setup();
+    tiered(&s);
+    let vc = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.open_syndicate(&vc, &founder, &2_000, &0);
+}
+
+#[test]
+#[should_panic(expected = "Syndicate closed")]
+fn test_closed_syndicate_rejects_members() {
+    let s = setup();
+    let lead = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+    let member = Address::generate(&s.env);
+    mint(&s, &member, 100);
+
+    s.client.open_syndicate(&lead, &founder, &1_000, &0);
+    s.client.close_syndicate(&lead, &founder);
+    s.client.join_syndicate(&member, &founder, &lead, &100);
+}
+
+#[test]
+#[should_panic(expected = "Below syndicate minimum ticket")]
+fn test_syndicate_minimum_applies_to_accepted_amount() {
+    let s = setup();
+    let lead = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    let member = Address::generate(&s.env);
+    mint(&s, &member, 200);
+
+    s.client.open_syndicate(&lead, &founder, &1_000, &100);
+    s.client.vc_invest(&lead, &founder, &950, &s.token);
+
+    // Only 50 fits under the funding cap
+    s.client.join_syndicate(&member, &founder, &lead, &200);
+}
+
+#[test]
+fn test_community_round_reserved_and_tracked_separately() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    let backer = Address::generate(&s.env);
+    mint(&s, &backer, 500);
+
+    s.client.set_community_allocation(&founder, &200);
+    s.client.update_community_user_cap(&s.admin, &150);
+
+    // VCs can only fill what is not reserved for the community
+    assert_eq!(s.client.vc_invest(&vc, &founder, &1_000, &s.token), 800);
+    assert_eq!(s.client.community_invest(&backer, &founder, &100), 100);
+
+    // ...and cannot use the community path to skip VC limits
+    assert!(s.client.try_community_invest(&vc, &founder, &100).is_err());
+
+    let status = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(status.total_allocated, 900);
+    assert_eq!(status.community_raised, 100);
+    assert_eq!(s.client.get_vc_data(&vc).unwrap().total_invested, 800);
+    assert_eq!(s.client.get_community_contribution(&founder, &backer), 100);
+
+    // Same escrow protection as VC money
+    s.client.emergency_shutdown(&s.admin);
+    assert_eq!(s.client.emergency_withdraw_investor(&backer, &founder), 100);
+}
+
+#[test]
+#[should_panic(expected = "Above community cap")]
+fn test_community_user_cap() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let backer = Address::generate(&s.env);
+    mint(&s, &backer, 500);
+
+    s.client.set_community_allocation(&founder, &500);
+    s.client.update_community_user_cap(&s.admin, &150);
+
+    s.client.community_invest(&backer, &founder, &100);
+    s.client.community_invest(&backer, &founder, &100);
+}
+
+#[test]
+fn test_community_user_cap_counts_escrowed_amount() {
+    let s = setup();
+    let founder = approved_startup(&s, 1_000);
+    let backer = Address::generate(&s.env);
+    mint(&s, &backer, 500);
+
+    s.client.update_platform_fee(&s.admin, &1_000);
+    s.client.set_community_allocation(&founder, &500);
+    s.client.update_community_user_cap(&s.admin, &90);
+
+    // 100 in, 10 platform fee, 90 escrowed: exactly at the cap
+    s.client.community_invest(&backer, &founder, &100);
+
+    let status = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(status.community_raised, 90);
+    assert_eq!(s.client.get_community_contribution(&founder, &backer), 90);
+}
+
+#[test]
+fn test_revenue_distributed_pro_rata() {
+    let s = setup();
+    s.client.set_invariant_checks(&s.admin, &true);
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 5_000);
+    mint(&s, &founder, 10_000);
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.deposit_revenue(&founder, &300, &s.token);
+
+    // vc2 joins after the first deposit and only shares in the second
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+    s.client.deposit_revenue(&founder, &400, &s.token);
+
+    assert_eq!(s.client.get_claimable_revenue(&vc1, &founder), 600);
+    assert_eq!(s.client.get_claimable_revenue(&vc2, &founder), 100);
+
+    assert_eq!(s.client.claim_revenue(&vc1, &founder), 600);
+    assert_eq!(s.client.get_claimable_revenue(&vc1, &founder), 0);
+
+    // Deposited revenue can still be claimed after shutdown
+    s.client.emergency_shutdown(&s.admin);
+    assert_eq!(s.client.claim_revenue(&vc2, &founder), 100);
+    assert!(s.client.reconcile(&s.token).balanced);
+}
+
+#[test]
+fn test_revenue_payback_cap() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    mint(&s, &founder, 10_000);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    s.client.set_payback_cap(&founder, &15_000);
+
+    assert_eq!(s.client.deposit_revenue(&founder, &1_000, &s.token), 1_000);
+    assert_eq!(s.client.deposit_revenue(&founder, &1_000, &s.token), 500);
+    assert!(s.client.try_deposit_revenue(&founder, &1, &s.token).is_err());
+    assert_eq!(s.client.get_revenue_pool(&founder).unwrap().total_deposited, 1_500);
+}
+
+#[test]
+fn test_reconcile_counts_revenue_per_token() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    let revenue_token = s
+        .env
+        .register_stellar_asset_contract_v2(s.admin.clone())
+        .address();
+    StellarAssetClient::new(&s.env, &revenue_token).mint(&founder, &500);
+
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    s.client.deposit_revenue(&founder, &500, &revenue_token);
+
+    let escrow = s.client.reconcile(&s.token);
+    assert_eq!(escrow.revenue, 0);
+    assert!(escrow.balanced);
+    assert!(s.client.is_solvent(&s.token));
+
+    assert_eq!(s.client.reconcile(&revenue_token).revenue, 500);
+}
+
+#[test]
+fn test_syndicate_carry_on_revenue() {
+    let s = setup();
+    let lead = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+    let member = Address::generate(&s.env);
+    mint(&s, &member, 1_000);
+    mint(&s, &founder, 1_000);
+
+    s.client.open_syndicate(&lead, &founder, &2_000, &0);
+    s.client.join_syndicate(&member, &founder, &lead, &1_000);
+    s.client.deposit_revenue(&founder, &500, &s.token);
+
+    assert_eq!(s.client.claim_revenue(&member, &founder), 400);
+    assert_eq!(s.client.claim_revenue(&lead, &founder), 100);
+}
+
+#[test]
+fn test_transfer_position_settles_payment() {
+    let s = setup();
+    let seller = new_vc(&s, 1_000);
+    let buyer = new_vc(&s, 500);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+    s.client.vc_invest(&seller, &founder, &1_000, &s.token);
+    s.client.transfer_position(&seller, &buyer, &founder, &400, &500);
+
+    assert_eq!(balance(&s, &seller), 500);
+    assert_eq!(s.client.get_shares(&founder, &seller), 60_000);
+    assert_eq!(s.client.get_shares(&founder, &buyer), 40_000);
+    assert_eq!(balance(&s, &buyer), 0);
+    assert_eq!(s.client.get_vc_data(&seller).unwrap().total_invested, 600);
+    assert_eq!(s.client.get_vc_data(&buyer).unwrap().total_invested, 400);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().investor_count, 2);
+
+    // Revenue follows the transferred position
+    mint(&s, &founder, 1_000);
+    s.client.deposit_revenue(&founder, &1_000, &s.token);
+    assert_eq!(s.client.get_claimable_revenue(&buyer, &founder), 400);
+
+    assert!(s.client.try_transfer_position(&buyer, &seller, &founder, &401, &0).is_err());
+}
+
+#[test]
+fn test_syndicated_position_keeps_carry_after_transfer() {
+    let s = setup();
+    let lead = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+    let member = Address::generate(&s.env);
+    let buyer = Address::generate(&s.env);
+    let outsider = new_vc(&s, 100);
+    mint(&s, &member, 1_000);
+    mint(&s, &founder, 1_000);
+
+    s.client.open_syndicate(&lead, &founder, &2_000, &0);
+    s.client.join_syndicate(&member, &founder, &lead, &900);
+    s.client.vc_invest(&outsider, &founder, &100, &s.token);
+    s.client.transfer_position(&member, &buyer, &founder, &450, &0);
+
+    assert_eq!(s.client.get_syndicate_contribution(&founder, &buyer), 450);
+    assert_eq!(s.client.get_syndicate(&founder, &lead).unwrap().member_count, 2);
+
+    // 20% carry still goes to the lead on the buyer's revenue
+    s.client.deposit_revenue(&founder, &1_000, &s.token);
+    assert_eq!(s.client.claim_revenue(&buyer, &founder), 360);
+
+    // A direct holder cannot take on a syndicated position
+    assert!(s.client.try_transfer_position(&member, &outsider, &founder, &100, &0).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Investor limit reached")]
+fn test_transfer_position_respects_investor_limit() {
+    let s = setup();
+    s.client.update_investment_limits(
+        &s.admin,
+        &InvestmentLimits {
+            overfunding_bps: 0,
+            min_ticket: 0,
+            max_ticket: 0,
+            max_investors: 1,
+        },
+    );
+    let seller = new_vc(&s, 1_000);
+    let buyer = Address::generate(&s.env);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.vc_invest(&seller, &founder, &1_000, &s.token);
+    s.client.transfer_position(&seller, &buyer, &founder, &400, &0);
+}
+
+#[test]
+fn test_right_of_first_refusal() {
+    let s = setup();
+    let seller = new_vc(&s, 1_000);
+    let buyer = new_vc(&s, 500);
+    let holder = Address::generate(&s.env);
+    mint(&s, &holder, 500);
+    let founder = approved_startup(&s, 1_000);
+
+    s.client.set_share_terms(&founder, &10_000, &1_000_000, &share_token(&s));
+    s.client.vc_invest(&seller, &founder, &1_000, &s.token);
+    s.client.set_right_of_first_refusal(&founder, &Some(holder.clone()), &86_400);
+
+    // Unannounced sales are blocked
+    assert!(s.client.try_transfer_position(&seller, &buyer, &founder, &500, &500).is_err());
+
+    s.client.offer_position(&seller, &founder, &500, &500);
+    assert!(s.client.try_transfer_position(&seller, &buyer, &founder, &500, &500).is_err());
+
+    s.client.exercise_rofr(&founder, &seller);
+    assert_eq!(s.client.get_vc_investment(&holder, &founder), 500);
+    assert_eq!(balance(&s, &holder), 0);
+    assert_eq!(balance(&s, &seller), 500);
+    // The holder takes the seller's shares without the seller signing again
+    assert_eq!(s.client.get_shares(&founder, &holder), 50_000);
+    assert_eq!(s.client.get_shares(&founder, &seller), 50_000);
+
+    // Once the window lapses the seller may sell to anyone on the offered terms
+    s.client.offer_position(&seller, &founder, &500, &500);
+    advance_time(&s, 86_401);
+    assert!(s.client.try_transfer_position(&seller, &buyer, &founder, &500, &400).is_err());
+    s.client.transfer_position(&seller, &buyer, &founder, &500, &500);
+    assert_eq!(s.client.get_vc_investment(&buyer, &founder), 500);
+    assert_eq!(s.client.get_vc_investment(&seller, &founder), 0);
+    assert_eq!(s.client.get_shares(&founder, &buyer), 50_000);
+}
+
+#[test]
+fn test_investor_termination_refunds_escrow() {
+    let s = setup();
+    s.client.set_invariant_checks(&s.admin, &true);
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    s.client.propose_termination(&vc2, &founder);
+    s.client.vote_termination(&vc1, &founder, &true);
+    assert!(s.client.try_vote_termination(&vc1, &founder, &false).is_err());
+    assert!(s.client.try_execute_termination(&founder).is_err());
+
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert!(s.client.execute_termination(&founder));
+    assert_eq!(
+        s.client.get_application(&0).unwrap().outcome,
+        ApplicationOutcome::Terminated
+    );
+
+    // 1_000 was unlocked but never claimed, so all 4_000 goes back
+    assert_eq!(s.client.claim_termination_refund(&vc1, &founder), 3_000);
+    assert_eq!(s.client.claim_termination_refund(&vc2, &founder), 1_000);
+    assert!(s.client.try_claim_termination_refund(&vc2, &founder).is_err());
+
+    assert!(s.client.try_claim_funds(&founder, &s.token).is_err());
+    assert!(s.client.try_unlock_milestone(&s.admin, &founder).is_err());
+
+    // Refunded positions stop earning revenue and leave the indexes
+    assert_eq!(s.client.get_vc_investment(&vc1, &founder), 0);
+    assert_eq!(s.client.get_revenue_pool(&founder).unwrap().total_units, 0);
+    assert_eq!(s.client.get_startup_investors(&founder, &0, &10).len(), 0);
+
+    // Only stakes remain; once they are returned the contract is empty
+    s.client.emergency_shutdown(&s.admin);
+    s.client.emergency_withdraw_stake(&vc1);
+    s.client.emergency_withdraw_stake(&vc2);
+    assert_eq!(balance(&s, &s.client.address), 0);
+}
+
+#[test]
+fn test_failed_termination_can_be_reproposed() {
+    let s = setup();
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+
+    s.client.propose_termination(&vc2, &founder);
+    s.client.vote_termination(&vc1, &founder, &false);
+
+    // Positions can't change hands while the vote is open
+    assert!(s.client.try_transfer_position(&vc1, &vc2, &founder, &1, &0).is_err());
+
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert!(!s.client.execute_termination(&founder));
+    assert!(s.client.get_termination_proposal(&founder).is_none());
+
+    s.client.propose_termination(&vc2, &founder);
+    s.client.vote_termination(&vc1, &founder, &true);
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert!(s.client.execute_termination(&founder));
+}
+
+fn new_arbiter(s: &Setup) -> Address {
+    let arbiter = Address::generate(&s.env);
+    mint(s, &arbiter, VC_STAKE);
+    s.client.register_arbiter(&arbiter);
+    arbiter
+}
+
+fn evidence(s: &Setup) -> String {
+    String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG")
+}
+
+#[test]
+fn test_refund_dispute_freezes_and_returns_escrow() {
+    let s = setup();
+    s.client.set_invariant_checks(&s.admin, &true);
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+    let arbiters = [new_arbiter(&s), new_arbiter(&s), new_arbiter(&s)];
+
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+
+    let dispute_id = s.client.open_dispute(&vc1, &founder, &ClaimType::Refund, &evidence(&s));
+    assert_eq!(s.client.get_dispute(&dispute_id).unwrap().amount, 4_000);
+
+    assert!(s.client.try_unlock_milestone(&s.admin, &founder).is_err());
+
+    s.client.vote_dispute(&arbiters[0], &dispute_id, &Ruling::Refund);
+    s.client.vote_dispute(&arbiters[1], &dispute_id, &Ruling::Refund);
+    s.client.vote_dispute(&arbiters[2], &dispute_id, &Ruling::Split);
+    assert!(s.client.try_withdraw_arbiter(&arbiters[0]).is_err());
+    assert!(s.client.try_resolve_dispute(&dispute_id).is_err());
+
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert_eq!(s.client.resolve_dispute(&dispute_id), DisputeStatus::Refunded);
+    assert!(s.client.get_open_dispute(&founder).is_none());
+
+    assert_eq!(s.client.claim_dispute_refund(&vc1, &founder), 3_000);
+    assert_eq!(s.client.claim_dispute_refund(&vc2, &founder), 1_000);
+    assert!(s.client.try_claim_dispute_refund(&vc2, &founder).is_err());
+
+    assert_eq!(s.client.withdraw_arbiter(&arbiters[0]), VC_STAKE);
+    assert!(s.client.reconcile(&s.token).balanced);
+}
+
+#[test]
+fn test_milestone_dispute_split_ruling() {
+    let s = setup();
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    let arbiters = [new_arbiter(&s), new_arbiter(&s)];
+
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+    let dispute_id = s.client.open_dispute(&founder, &founder, &ClaimType::MilestoneUnlock, &evidence(&s));
+    assert!(s.client.try_open_dispute(&vc, &founder, &ClaimType::Refund, &evidence(&s)).is_err());
+
+    // Tied votes split the milestone tranche
+    s.client.vote_dispute(&arbiters[0], &dispute_id, &Ruling::Release);
+    s.client.vote_dispute(&arbiters[1], &dispute_id, &Ruling::Refund);
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert_eq!(s.client.resolve_dispute(&dispute_id), DisputeStatus::Split);
+
+    let startup = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(startup.unlocked_balance, 500);
+    assert_eq!(startup.current_milestone, 1);
+
+    s.client.claim_funds(&founder, &s.token);
+    assert_eq!(balance(&s, &founder), 500);
+    assert_eq!(s.client.claim_dispute_refund(&vc, &founder), 500);
+}
+
+#[test]
+fn test_dispute_funds_recoverable_after_shutdown() {
+    let s = setup();
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    let arbiters = [new_arbiter(&s), new_arbiter(&s)];
+
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+    let dispute_id = s.client.open_dispute(&vc, &founder, &ClaimType::Refund, &evidence(&s));
+    s.client.vote_dispute(&arbiters[0], &dispute_id, &Ruling::Refund);
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    s.client.resolve_dispute(&dispute_id);
+
+    // A second dispute is left open when the contract shuts down
+    let founder2 = approved_startup(&s, 1_000);
+    let vc2 = new_vc(&s, 1_000);
+    s.client.vc_invest(&vc2, &founder2, &1_000, &s.token);
+    let open_id = s.client.open_dispute(&vc2, &founder2, &ClaimType::Refund, &evidence(&s));
+    s.client.vote_dispute(&arbiters[1], &open_id, &Ruling::Refund);
+
+    s.client.emergency_shutdown(&s.admin);
+    assert_eq!(s.client.claim_dispute_refund(&vc, &founder), 4_000);
+    assert_eq!(s.client.withdraw_arbiter(&arbiters[0]), VC_STAKE);
+    assert_eq!(s.client.withdraw_arbiter(&arbiters[1]), VC_STAKE);
+}
+
+#[test]
+fn test_dispute_opening_limits() {
+    let s = setup();
+    let whale = new_vc(&s, 9_500);
+    let minnow = new_vc(&s, 500);
+    let founder = approved_startup(&s, 10_000);
+
+    s.client.vc_invest(&whale, &founder, &9_500, &s.token);
+    s.client.vc_invest(&minnow, &founder, &500, &s.token);
+
+    // 5% of the round is below the default 10% minimum
+    assert!(s
+        .client
+        .try_open_dispute(&minnow, &founder, &ClaimType::Refund, &evidence(&s))
+        .is_err());
+
+    // A dismissed opener has to wait before trying again
+    let dispute_id = s.client.open_dispute(&whale, &founder, &ClaimType::Refund, &evidence(&s));
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert_eq!(s.client.resolve_dispute(&dispute_id), DisputeStatus::Dismissed);
+    assert!(s
+        .client
+        .try_open_dispute(&whale, &founder, &ClaimType::Refund, &evidence(&s))
+        .is_err());
+
+    advance_time(&s, 7 * 24 * 60 * 60);
+    s.client.open_dispute(&whale, &founder, &ClaimType::Refund, &evidence(&s));
+}
+
+/// Stand-in for an oracle contract that relays KPI readings
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn report(env: Env, deco: Address, founder: Address, kpi: Symbol, value: i128) -> bool {
+        DeCoV2Client::new(&env, &deco).submit_kpi_attestation(
+            &env.current_contract_address(),
+            &founder,
+            &kpi,
+            &value,
+        )
+    }
+}
+
+#[test]
+fn test_kpi_attestation_unlocks_milestone() {
+    let s = setup();
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    let oracle_id = s.env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(&s.env, &oracle_id);
+
+    assert!(s
+        .client
+        .try_set_kpi_milestone(&founder, &0, &symbol_short!("mrr"), &10_000, &oracle_id)
+        .is_err());
+    s.client.set_oracle(&s.admin, &oracle_id, &true);
+    s.client.set_kpi_milestone(&founder, &0, &symbol_short!("mrr"), &10_000, &oracle_id);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+
+    // Attestations wait for the milestone interval too
+    assert!(oracle
+        .try_report(&s.client.address, &founder, &symbol_short!("mrr"), &12_000)
+        .is_err());
+
+    // No admin unlock for a KPI milestone
+    advance_time(&s, MILESTONE_INTERVAL);
+    assert!(s.client.try_unlock_milestone(&s.admin, &founder).is_err());
+
+    assert!(!oracle.report(&s.client.address, &founder, &symbol_short!("mrr"), &9_000));
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().current_milestone, 0);
+
+    assert!(oracle.report(&s.client.address, &founder, &symbol_short!("mrr"), &12_000));
+    let startup = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(startup.current_milestone, 1);
+    assert_eq!(startup.unlocked_balance, 1_000);
+    assert_eq!(s.client.get_kpi_attestation(&founder, &0).unwrap().value, 12_000);
+
+    // Milestone 1 has no KPI and follows the regular admin flow
+    assert!(oracle
+        .try_report(&s.client.address, &founder, &symbol_short!("mrr"), &12_000)
+        .is_err());
+}
+
+#[test]
+fn test_kpi_attestation_from_wrong_oracle_rejected() {
+    let s = setup();
+    let founder = approved_startup(&s, 4_000);
+    let oracle = Address::generate(&s.env);
+    let other = Address::generate(&s.env);
+    s.client.set_oracle(&s.admin, &oracle, &true);
+    s.client.set_oracle(&s.admin, &other, &true);
+    s.client.set_kpi_milestone(&founder, &0, &symbol_short!("users"), &500, &oracle);
+    advance_time(&s, MILESTONE_INTERVAL);
+
+    // Nothing to release before anyone has invested
+    assert!(s
+        .client
+        .try_submit_kpi_attestation(&oracle, &founder, &symbol_short!("users"), &1_000)
+        .is_err());
+
+    let vc = new_vc(&s, 4_000);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+
+    assert!(s
+        .client
+        .try_submit_kpi_attestation(&other, &founder, &symbol_short!("users"), &1_000)
+        .is_err());
+    assert!(s
+        .client
+        .try_submit_kpi_attestation(&oracle, &founder, &symbol_short!("mrr"), &1_000)
+        .is_err());
+    assert!(s
+        .client
+        .submit_kpi_attestation(&oracle, &founder, &symbol_short!("users"), &1_000));
+}
+
+fn deadline_rules(s: &Setup, penalty: MissedMilestonePenalty) {
+    s.client.update_deadline_rules(
+        &s.admin,
+        &DeadlineRules {
+            deadline: 60 * 24 * 60 * 60,
+            grace_period: 7 * 24 * 60 * 60,
+            penalty,
+            return_bps: 5_000,
+        },
+    );
+}
+
+#[test]
+fn test_missed_milestone_partial_return() {
+    let s = setup();
+    deadline_rules(&s, MissedMilestonePenalty::PartialReturn);
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+
+    // Past the deadline but still inside the grace period
+    advance_time(&s, 61 * 24 * 60 * 60);
+    assert!(s.client.try_enforce_milestone_deadline(&founder).is_err());
+
+    advance_time(&s, 7 * 24 * 60 * 60);
+    assert_eq!(
+        s.client.enforce_milestone_deadline(&founder),
+        MissedMilestonePenalty::PartialReturn
+    );
+    assert!(s.client.try_enforce_milestone_deadline(&founder).is_err());
+    assert_eq!(s.client.claim_dispute_refund(&vc, &founder), 500);
+
+    // The late unlock releases the full tranche; the last one absorbs the return
+    s.client.unlock_milestone(&s.admin, &founder);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().unlocked_balance, 1_000);
+}
+
+#[test]
+fn test_missed_milestone_escalates_to_investor_vote() {
+    let s = setup();
+    deadline_rules(&s, MissedMilestonePenalty::InvestorVote);
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+
+    advance_time(&s, 68 * 24 * 60 * 60);
+    s.client.enforce_milestone_deadline(&founder);
+    assert!(s.client.get_termination_proposal(&founder).is_some());
+
+    s.client.vote_termination(&vc, &founder, &true);
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert!(s.client.execute_termination(&founder));
+}
+
+#[test]
+fn test_extension_approved_by_investors_defers_at_risk() {
+    let s = setup();
+    deadline_rules(&s, MissedMilestonePenalty::AtRisk);
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 4_000);
+    s.client.vc_invest(&vc1, &founder, &3_000, &s.token);
+    s.client.vc_invest(&vc2, &founder, &1_000, &s.token);
+
+    let deadline = s.client.get_milestone_deadline(&founder);
+    s.client.request_extension(&founder, &(30 * 24 * 60 * 60));
+    s.client.vote_extension(&vc2, &founder, &true);
+    assert!(s.client.get_extension_request(&founder).is_some());
+    s.client.vote_extension(&vc1, &founder, &true);
+    assert!(s.client.get_extension_request(&founder).is_none());
+    assert_eq!(s.client.get_milestone_deadline(&founder), deadline + 30 * 24 * 60 * 60);
+
+    advance_time(&s, 68 * 24 * 60 * 60);
+    assert!(s.client.try_enforce_milestone_deadline(&founder).is_err());
+
+    advance_time(&s, 30 * 24 * 60 * 60);
+    s.client.enforce_milestone_deadline(&founder);
+    assert!(s.client.is_at_risk(&founder));
+
+    s.client.unlock_milestone(&s.admin, &founder);
+    assert!(!s.client.is_at_risk(&founder));
+}
+
+#[test]
+fn test_unanswered_extension_request_expires() {
+    let s = setup();
+    let seller = new_vc(&s, 1_000);
+    let buyer = new_vc(&s, 0);
+    let founder = approved_startup(&s, 1_000);
+    s.client.vc_invest(&seller, &founder, &1_000, &s.token);
+
+    s.client.request_extension(&founder, &(30 * 24 * 60 * 60));
+    assert!(s.client.try_transfer_position(&seller, &buyer, &founder, &100, &0).is_err());
+    assert!(s.client.try_request_extension(&founder, &(30 * 24 * 60 * 60)).is_err());
+
+    // Nobody voted within the voting period
+    advance_time(&s, 7 * 24 * 60 * 60 + 1);
+    assert!(s.client.try_vote_extension(&seller, &founder, &true).is_err());
+    s.client.transfer_position(&seller, &buyer, &founder, &100, &0);
+    s.client.request_extension(&founder, &(30 * 24 * 60 * 60));
+}
+
+#[test]
+fn test_claim_amount_pays_vendor_and_logs() {
+    let s = setup();
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    let vendor = Address::generate(&s.env);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    let invoice = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    assert_eq!(s.client.claim_amount(&founder, &300, &vendor, &invoice), 300);
+    assert!(s
+        .client
+        .try_claim_amount(&founder, &701, &vendor, &invoice)
+        .is_err());
+    s.client.claim_funds(&founder, &s.token);
+
+    assert_eq!(balance(&s, &vendor), 300);
+    assert_eq!(balance(&s, &founder), 700);
+
+    assert_eq!(s.client.get_spending_count(&founder), 2);
+    let log = s.client.get_spending_log(&founder, &0, &10);
+    assert_eq!(log.len(), 2);
+    let first = log.get_unchecked(0);
+    assert_eq!(first.recipient, vendor);
+    assert_eq!(first.amount, 300);
+    assert_eq!(first.purpose_cid, Some(invoice));
+    assert_eq!(log.get_unchecked(1).purpose_cid, None);
+
+    let page = s.client.get_spending_log(&founder, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).amount, 700);
+}
+
+#[test]
+fn test_team_claim_amount_pays_vendor() {
+    let s = setup();
+    let vc = new_vc(&s, 4_000);
+    let founder = approved_startup(&s, 4_000);
+    let vendor = Address::generate(&s.env);
+    let members = [Address::generate(&s.env), Address::generate(&s.env)];
+    team_of(&s, &founder, &members, &[5_000, 5_000], 2);
+    s.client.vc_invest(&vc, &founder, &4_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    s.client.unlock_milestone(&s.admin, &founder);
+
+    let invoice = String::from_str(&s.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    assert!(s
+        .client
+        .try_claim_amount(&founder, &300, &vendor, &invoice)
+        .is_err());
+    assert!(s
+        .client
+        .try_claim_amount_team(&founder, &Vec::from_slice(&s.env, &members[..1]), &300, &vendor, &invoice)
+        .is_err());
+
+    let signers = Vec::from_slice(&s.env, &members);
+    assert_eq!(
+        s.client.claim_amount_team(&founder, &signers, &300, &vendor, &invoice),
+        300
+    );
+    assert_eq!(balance(&s, &vendor), 300);
+    assert_eq!(s.client.get_spending_log(&founder, &0, &1).get_unchecked(0).recipient, vendor);
+}
+
+#[test]
+fn test_portfolio_and_investor_indexes() {
+    let s = setup();
+    let vc1 = new_vc(&s, 3_000);
+    let vc2 = new_vc(&s, 1_000);
+    let founder1 = approved_startup(&s, 2_000);
+    let founder2 = approved_startup(&s, 2_000);
+
+    s.client.vc_invest(&vc1, &founder1, &1_000, &s.token);
+    s.client.vc_invest(&vc1, &founder2, &2_000, &s.token);
+    s.client.vc_invest(&vc2, &founder1, &1_000, &s.token);
+
+    let portfolio = s.client.get_vc_portfolio(&vc1, &0, &10);
+    assert_eq!(portfolio.len(), 2);
+    let second = portfolio.get_unchecked(1);
+    assert_eq!(second.startup, founder2);
+    assert_eq!(second.amount, 2_000);
+    assert_eq!(second.status, ApplicationOutcome::Approved);
+
+    let page = s.client.get_vc_portfolio(&vc1, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).startup, founder2);
+    assert_eq!(s.client.get_vc_portfolio(&vc1, &5, &10).len(), 0);
+
+    let investors = s.client.get_startup_investors(&founder1, &0, &10);
+    assert_eq!(investors.len(), 2);
+    assert_eq!(investors.get_unchecked(1).investor, vc2);
+
+    // Selling the whole position drops the startup from the seller's indexes
+    s.client.transfer_position(&vc2, &vc1, &founder1, &1_000, &0);
+    let investors = s.client.get_startup_investors(&founder1, &0, &10);
+    assert_eq!(investors.len(), 1);
+    assert_eq!(investors.get_unchecked(0).amount, 2_000);
+    assert_eq!(s.client.get_vc_portfolio(&vc2, &0, &10).len(), 0);
+
+    // Closing a position doesn't shift later entries
+    s.client.emergency_shutdown(&s.admin);
+    s.client.emergency_withdraw_investor(&vc1, &founder1);
+    let page = s.client.get_vc_portfolio(&vc1, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).startup, founder2);
+    assert_eq!(s.client.get_vc_portfolio(&vc1, &0, &1).len(), 0);
+}
+
+#[test]
+fn test_startup_details_for_viewer() {
+    let s = setup();
+    let voter = Address::generate(&s.env);
+    mint(&s, &voter, MIN_VOTE_BALANCE);
+    let vc = new_vc(&s, 1_000);
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 4_000);
+
+    advance_time(&s, 24 * 60 * 60);
+    s.client.vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token);
+
+    let details = s.client.get_startup_details(&founder, &voter);
+    assert_eq!(details.status, ApplicationOutcome::Pending);
+    assert_eq!(details.voting_time_remaining, 6 * 24 * 60 * 60);
+    assert_eq!(details.viewer_vote, Some(true));
+
+    s.client.approve_application(&s.admin, &founder);
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+
+    let details = s.client.get_startup_details(&founder, &vc);
+    assert_eq!(details.funding_progress_bps, 2_500);
+    assert_eq!(details.viewer_vote, None);
+    assert_eq!(details.viewer_investment, 1_000);
+    assert_eq!(details.milestones.len(), 4);
+    assert!(!details.milestones.get_unchecked(0).eligible);
+
+    advance_time(&s, MILESTONE_INTERVAL);
+    let details = s.client.get_startup_details(&founder, &vc);
+    let first = details.milestones.get_unchecked(0);
+    assert!(first.eligible);
+    assert_eq!(first.amount, 250);
+    assert!(!details.milestones.get_unchecked(1).eligible);
+
+    // Released amounts stay put; later tranches follow the larger round
+    s.client.unlock_milestone(&s.admin, &founder);
+    let vc2 = new_vc(&s, 1_002);
+    s.client.vc_invest(&vc2, &founder, &1_002, &s.token);
+    let details = s.client.get_startup_details(&founder, &vc);
+    let amounts: std::vec::Vec<i128> = details.milestones.iter().map(|m| m.amount).collect();
+    assert_eq!(amounts, [250, 500, 500, 752]);
+}
+
+#[test]
+fn test_conflicted_votes_rejected_with_typed_error() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    let member = Address::generate(&s.env);
+    let related = Address::generate(&s.env);
+    for voter in [&founder, &member, &related] {
+        mint(&s, voter, MIN_VOTE_BALANCE);
+    }
+    apply_as(&s, &founder, 1_000);
+    team_of(&s, &founder, &[founder.clone(), member.clone()], &[5_000, 5_000], 1);
+    s.client.set_related_party(&s.admin, &founder, &related, &true);
+
+    assert_eq!(
+        s.client.try_vote(&founder, &founder, &true, &Vec::new(&s.env), &s.token),
+        Err(Ok(VoteError::FounderVote.into()))
+    );
+    assert_eq!(
+        s.client.try_vote(&member, &founder, &true, &Vec::new(&s.env), &s.token),
+        Err(Ok(VoteError::TeamMemberVote.into()))
+    );
+    assert_eq!(
+        s.client.try_vote(&related, &founder, &true, &Vec::new(&s.env), &s.token),
+        Err(Ok(VoteError::RelatedParty.into()))
+    );
+
+    s.client.update_conflict_rules(
+        &s.admin,
+        &ConflictRules { bar_team: false, investor_policy: InvestorVotePolicy::Barred },
+    );
+    s.client.vote(&founder, &founder, &true, &Vec::new(&s.env), &s.token);
+}
+
+#[test]
+fn test_invested_vc_votes_barred_or_tallied_separately() {
+    let s = setup();
+    let vc = new_vc(&s, 1_000);
+    let founder = approved_startup(&s, 1_000);
+    s.client.vc_invest(&vc, &founder, &1_000, &s.token);
+    advance_time(&s, MILESTONE_INTERVAL);
+    for _ in 0..4 {
+        s.client.unlock_milestone(&s.admin, &founder);
+        advance_time(&s, MILESTONE_INTERVAL);
+    }
+    s.client.claim_funds(&founder, &s.token);
+
+    // The VC keeps its position into the founder's next round
+    apply_as(&s, &founder, 1_000);
+    mint(&s, &vc, MIN_VOTE_BALANCE);
+    assert_eq!(
+        s.client.try_vote(&vc, &founder, &true, &Vec::new(&s.env), &s.token),
+        Err(Ok(VoteError::InvestorVote.into()))
+    );
+
+    s.client.update_conflict_rules(
+        &s.admin,
+        &ConflictRules { bar_team: true, investor_policy: InvestorVotePolicy::Separate },
+    );
+    s.client.vote(&vc, &founder, &true, &Vec::new(&s.env), &s.token);
+    assert_eq!(s.client.get_investor_tally(&founder).yes_votes, 1);
+    assert_eq!(s.client.get_startup_status(&founder).unwrap().yes_votes, 0);
+    assert!(s.client.has_voted(&vc, &founder));
+}
+
+fn signed_vote(s: &Setup, key: &SigningKey, voter: &Address, founder: &Address, vote_yes: bool, nonce: u64) -> SignedVote {
+    let message: std::vec::Vec<u8> = s
+        .client
+        .signed_vote_message(voter, founder, &vote_yes, &nonce)
+        .iter()
+        .collect();
+
+    SignedVote {
+        voter: voter.clone(),
+        founder: founder.clone(),
+        vote_yes,
+        nonce,
+        signature: BytesN::from_array(&s.env, &key.sign(&message).to_bytes()),
+        proof: Vec::new(&s.env),
+    }
+}
+
+fn vote_signer(s: &Setup, seed: u8) -> (Address, SigningKey) {
+    let voter = Address::generate(&s.env);
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    mint(s, &voter, MIN_VOTE_BALANCE);
+    s.client
+        .register_vote_key(&voter, &BytesN::from_array(&s.env, &key.verifying_key().to_bytes()));
+    (voter, key)
+}
+
+#[test]
+fn test_relayed_signed_votes() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let (alice, alice_key) = vote_signer(&s, 1);
+    let (bob, bob_key) = vote_signer(&s, 2);
+
+    let batch = Vec::from_array(
+        &s.env,
+        [
+            signed_vote(&s, &alice_key, &alice, &founder, true, 0),
+            signed_vote(&s, &bob_key, &bob, &founder, false, 0),
+        ],
+    );
+    assert_eq!(s.client.submit_signed_votes(&batch), 2);
+
+    let startup = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(startup.yes_votes, 1);
+    assert_eq!(startup.no_votes, 1);
+    assert_eq!(s.client.get_vote_nonce(&alice), 1);
+
+    // Replaying the same signed vote fails on the nonce
+    let replay = Vec::from_array(&s.env, [signed_vote(&s, &alice_key, &alice, &founder, true, 0)]);
+    assert!(s.client.try_submit_signed_votes(&replay).is_err());
+}
+
+#[test]
+fn test_signed_vote_with_wrong_key_rejected() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let (alice, _) = vote_signer(&s, 1);
+    let mallory_key = SigningKey::from_bytes(&[9; 32]);
+
+    let forged = Vec::from_array(&s.env, [signed_vote(&s, &mallory_key, &alice, &founder, true, 0)]);
+    assert!(s.client.try_submit_signed_votes(&forged).is_err());
+    assert!(!s.client.has_voted(&alice, &founder));
+    assert_eq!(s.client.get_vote_nonce(&alice), 0);
+}
+
+#[test]
+fn test_signed_vote_bound_to_voter() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let (alice, alice_key) = vote_signer(&s, 1);
+    let (bob, _) = vote_signer(&s, 2);
+
+    // A key owned by another voter cannot be registered
+    let alice_public = BytesN::from_array(&s.env, &alice_key.verifying_key().to_bytes());
+    assert!(s.client.try_register_vote_key(&bob, &alice_public).is_err());
+
+    // Alice's signature does not carry over to Bob's vote
+    let mut stolen = signed_vote(&s, &alice_key, &alice, &founder, true, 0);
+    stolen.voter = bob.clone();
+    assert!(s.client.try_submit_signed_votes(&Vec::from_array(&s.env, [stolen])).is_err());
+
+    // Rotating frees the old key for someone else
+    let rotated = SigningKey::from_bytes(&[3; 32]);
+    s.client
+        .register_vote_key(&alice, &BytesN::from_array(&s.env, &rotated.verifying_key().to_bytes()));
+    s.client.register_vote_key(&bob, &alice_public);
+    assert_eq!(s.client.get_vote_key(&bob), Some(alice_public));
+}
+
+#[test]
+fn test_vote_balance_checked_in_config_token() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let (voter, key) = vote_signer(&s, 1);
+
+    let other_admin = Address::generate(&s.env);
+    let other_token = s.env.register_stellar_asset_contract_v2(other_admin).address();
+    assert!(s
+        .client
+        .try_vote(&voter, &founder, &true, &Vec::new(&s.env), &other_token)
+        .is_err());
+
+    // Without a balance in the escrow token the relayed vote is refused
+    let broke = Address::generate(&s.env);
+    let broke_key = SigningKey::from_bytes(&[4; 32]);
+    s.client
+        .register_vote_key(&broke, &BytesN::from_array(&s.env, &broke_key.verifying_key().to_bytes()));
+    let batch = Vec::from_array(&s.env, [signed_vote(&s, &broke_key, &broke, &founder, true, 0)]);
+    assert!(s.client.try_submit_signed_votes(&batch).is_err());
+
+    let batch = Vec::from_array(&s.env, [signed_vote(&s, &key, &voter, &founder, true, 0)]);
+    assert_eq!(s.client.submit_signed_votes(&batch), 1);
+}
+
+fn leaf(s: &Setup, voter: &Address) -> BytesN<32> {
+    let mut bytes = soroban_sdk::Bytes::from_array(&s.env, &[0x00]);
+    bytes.append(&voter.clone().to_xdr(&s.env));
+    s.env.crypto().sha256(&bytes).into()
+}
+
+fn hash_pair(s: &Setup, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = soroban_sdk::Bytes::from_array(&s.env, &[0x01]);
+    pair.append(&soroban_sdk::Bytes::from(first.clone()));
+    pair.append(&soroban_sdk::Bytes::from(second.clone()));
+    s.env.crypto().sha256(&pair).into()
+}
+
+#[test]
+fn test_merkle_allowlisted_voting() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let [alice, bob, carol, outsider] = [0; 4].map(|_| {
+        let voter = Address::generate(&s.env);
+        mint(&s, &voter, MIN_VOTE_BALANCE);
+        voter
+    });
+
+    let (alice_leaf, bob_leaf, carol_leaf) = (leaf(&s, &alice), leaf(&s, &bob), leaf(&s, &carol));
+    let alice_bob = hash_pair(&s, &alice_leaf, &bob_leaf);
+    let root = hash_pair(&s, &alice_bob, &carol_leaf);
+    s.client.set_voter_root(&s.admin, &0, &root);
+
+    let alice_proof = Vec::from_array(&s.env, [bob_leaf.clone(), carol_leaf.clone()]);
+    s.client.vote(&alice, &founder, &true, &alice_proof, &s.token);
+    assert!(s.client.is_eligible_voter(&0, &alice));
+
+    s.client.vote(&carol, &founder, &false, &Vec::from_array(&s.env, [alice_bob]), &s.token);
+
+    // A valid proof for someone else doesn't make the outsider eligible
+    assert!(s
+        .client
+        .try_vote(&outsider, &founder, &true, &alice_proof, &s.token)
+        .is_err());
+    assert!(s
+        .client
+        .try_vote(&bob, &founder, &true, &Vec::new(&s.env), &s.token)
+        .is_err());
+
+    let startup = s.client.get_startup_status(&founder).unwrap();
+    assert_eq!(startup.yes_votes, 1);
+    assert_eq!(startup.no_votes, 1);
+}
+
+#[test]
+fn test_recorded_eligibility_covers_relayed_votes() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let (alice, alice_key) = vote_signer(&s, 1);
+    let other = Address::generate(&s.env);
+
+    let alice_leaf = leaf(&s, &alice);
+    let other_leaf = leaf(&s, &other);
+    s.client.set_voter_root(&s.admin, &0, &hash_pair(&s, &alice_leaf, &other_leaf));
+
+    let mut signed = signed_vote(&s, &alice_key, &alice, &founder, true, 0);
+    assert!(s
+        .client
+        .try_submit_signed_votes(&Vec::from_array(&s.env, [signed.clone()]))
+        .is_err());
+
+    signed.proof = Vec::from_array(&s.env, [other_leaf]);
+    s.client.submit_signed_votes(&Vec::from_array(&s.env, [signed]));
+    assert!(s.client.is_eligible_voter(&0, &alice));
+}
+
+#[test]
+fn test_voter_root_locked_once_voting_starts() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let voter = Address::generate(&s.env);
+    mint(&s, &voter, MIN_VOTE_BALANCE);
+
+    let open_root = leaf(&s, &voter);
+    s.client.set_voter_root(&s.admin, &0, &open_root);
+    s.client.vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token);
+
+    let other_root = leaf(&s, &founder);
+    assert!(s.client.try_set_voter_root(&s.admin, &0, &other_root).is_err());
+
+    let late = Address::generate(&s.env);
+    apply_as(&s, &late, 1_000);
+    advance_time(&s, 8 * 24 * 60 * 60);
+    assert!(s.client.try_set_voter_root(&s.admin, &1, &other_root).is_err());
+}
+
+#[test]
+fn test_required_allowlist_holds_voting_until_published() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let voter = Address::generate(&s.env);
+    mint(&s, &voter, MIN_VOTE_BALANCE);
+
+    // An early vote can no longer lock the round open
+    s.client.set_allowlist_required(&s.admin, &true);
+    assert!(s
+        .client
+        .try_vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token)
+        .is_err());
+
+    s.client.set_voter_root(&s.admin, &0, &leaf(&s, &voter));
+    s.client.vote(&voter, &founder, &true, &Vec::new(&s.env), &s.token);
+    assert!(s.client.is_eligible_voter(&0, &voter));
+}
+
+#[test]
+fn test_untagged_merkle_root_rejected() {
+    let s = setup();
+    let founder = Address::generate(&s.env);
+    apply_as(&s, &founder, 1_000);
+    let [alice, bob] = [0; 2].map(|_| {
+        let voter = Address::generate(&s.env);
+        mint(&s, &voter, MIN_VOTE_BALANCE);
+        voter
+    });
+
+    // A tree built without the leaf and node tags no longer verifies
+    let untagged = |voter: &Address| -> BytesN<32> { s.env.crypto().sha256(&voter.clone().to_xdr(&s.env)).into() };
+    let (alice_leaf, bob_leaf) = (untagged(&alice), untagged(&bob));
+    let (first, second) = if alice_leaf <= bob_leaf { (&alice_leaf, &bob_leaf) } else { (&bob_leaf, &alice_leaf) };
+    let mut pair = soroban_sdk::Bytes::from(first.clone());
+    pair.append(&soroban_sdk::Bytes::from(second.clone()));
+    s.client.set_voter_root(&s.admin, &0, &s.env.crypto().sha256(&pair).into());
+
+    assert!(s
+        .client
+        .try_vote(&alice, &founder, &true, &Vec::from_array(&s.env, [bob_leaf]), &s.token)
+        .is_err());
+    assert!(!s.client.is_eligible_voter(&0, &alice));
+}